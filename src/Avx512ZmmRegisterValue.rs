@@ -0,0 +1,323 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// An `AVX-512` `ZMM` register value.
+///
+/// The low 256 bits are the corresponding `YMM` register.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct Avx512ZmmRegisterValue
+{
+	/// Register value (little endian).
+	pub register_value: [u8; 64],
+}
+
+impl Default for Avx512ZmmRegisterValue
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			register_value: [0; 64],
+		}
+	}
+}
+
+impl Debug for Avx512ZmmRegisterValue
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_struct("Avx512ZmmRegisterValue").field("f32x16", &self.f32x16()).finish()
+	}
+}
+
+impl PartialEq for Avx512ZmmRegisterValue
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.register_value[..] == other.register_value[..]
+	}
+}
+
+impl Eq for Avx512ZmmRegisterValue
+{
+}
+
+impl PartialOrd for Avx512ZmmRegisterValue
+{
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for Avx512ZmmRegisterValue
+{
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		self.register_value[..].cmp(&other.register_value[..])
+	}
+}
+
+impl Hash for Avx512ZmmRegisterValue
+{
+	#[inline(always)]
+	fn hash<H: Hasher>(&self, state: &mut H)
+	{
+		self.register_value[..].hash(state)
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<__m512> for Avx512ZmmRegisterValue
+{
+	#[inline(always)]
+	fn from(value: __m512) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<Avx512ZmmRegisterValue> for __m512
+{
+	#[inline(always)]
+	fn from(value: Avx512ZmmRegisterValue) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<__m512i> for Avx512ZmmRegisterValue
+{
+	#[inline(always)]
+	fn from(value: __m512i) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<Avx512ZmmRegisterValue> for __m512i
+{
+	#[inline(always)]
+	fn from(value: Avx512ZmmRegisterValue) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<__m512d> for Avx512ZmmRegisterValue
+{
+	#[inline(always)]
+	fn from(value: __m512d) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<Avx512ZmmRegisterValue> for __m512d
+{
+	#[inline(always)]
+	fn from(value: Avx512ZmmRegisterValue) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+impl Avx512ZmmRegisterValue
+{
+	/// Low 256 bits (the `YMM` register).
+	#[inline(always)]
+	pub fn low_256_bits(&self) -> AvxYmmRegisterValue
+	{
+		AvxYmmRegisterValue
+		{
+			register_value: *array_ref!(self.register_value, 0, 32),
+		}
+	}
+
+	/// High 256 bits.
+	#[inline(always)]
+	pub fn high_256_bits(&self) -> &[u8; 32]
+	{
+		array_ref!(self.register_value, 32, 32)
+	}
+
+	/// Lanes as 16-bit IEEE 754 half precision floating point (`f16`) values (`f16x32`).
+	#[inline(always)]
+	pub fn f16x32(&self) -> [Float16; 32]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from 16-bit IEEE 754 half precision floating point (`f16`) values (`f16x32`).
+	#[inline(always)]
+	pub fn set_f16x32(&mut self, lanes: [Float16; 32])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as 16-bit brain floating point (`bf16`) values (`bf16x32`).
+	#[inline(always)]
+	pub fn bf16x32(&self) -> [BFloat16; 32]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from 16-bit brain floating point (`bf16`) values (`bf16x32`).
+	#[inline(always)]
+	pub fn set_bf16x32(&mut self, lanes: [BFloat16; 32])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `f32` values (`f32x16`).
+	#[inline(always)]
+	pub fn f32x16(&self) -> [f32; 16]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `f32` values (`f32x16`).
+	#[inline(always)]
+	pub fn set_f32x16(&mut self, lanes: [f32; 16])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `f64` values (`f64x8`).
+	#[inline(always)]
+	pub fn f64x8(&self) -> [f64; 8]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `f64` values (`f64x8`).
+	#[inline(always)]
+	pub fn set_f64x8(&mut self, lanes: [f64; 8])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `i8` values (`i8x64`).
+	#[inline(always)]
+	pub fn i8x64(&self) -> [i8; 64]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `i8` values (`i8x64`).
+	#[inline(always)]
+	pub fn set_i8x64(&mut self, lanes: [i8; 64])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `i16` values (`i16x32`).
+	#[inline(always)]
+	pub fn i16x32(&self) -> [i16; 32]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `i16` values (`i16x32`).
+	#[inline(always)]
+	pub fn set_i16x32(&mut self, lanes: [i16; 32])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `i32` values (`i32x16`).
+	#[inline(always)]
+	pub fn i32x16(&self) -> [i32; 16]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `i32` values (`i32x16`).
+	#[inline(always)]
+	pub fn set_i32x16(&mut self, lanes: [i32; 16])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `i64` values (`i64x8`).
+	#[inline(always)]
+	pub fn i64x8(&self) -> [i64; 8]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `i64` values (`i64x8`).
+	#[inline(always)]
+	pub fn set_i64x8(&mut self, lanes: [i64; 8])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `u8` values (`u8x64`).
+	#[inline(always)]
+	pub fn u8x64(&self) -> [u8; 64]
+	{
+		self.register_value
+	}
+
+	/// Set lanes from `u8` values (`u8x64`).
+	#[inline(always)]
+	pub fn set_u8x64(&mut self, lanes: [u8; 64])
+	{
+		self.register_value = lanes
+	}
+
+	/// Lanes as `u16` values (`u16x32`).
+	#[inline(always)]
+	pub fn u16x32(&self) -> [u16; 32]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `u16` values (`u16x32`).
+	#[inline(always)]
+	pub fn set_u16x32(&mut self, lanes: [u16; 32])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `u32` values (`u32x16`).
+	#[inline(always)]
+	pub fn u32x16(&self) -> [u32; 16]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `u32` values (`u32x16`).
+	#[inline(always)]
+	pub fn set_u32x16(&mut self, lanes: [u32; 16])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `u64` values (`u64x8`).
+	#[inline(always)]
+	pub fn u64x8(&self) -> [u64; 8]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `u64` values (`u64x8`).
+	#[inline(always)]
+	pub fn set_u64x8(&mut self, lanes: [u64; 8])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+}
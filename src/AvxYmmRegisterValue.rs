@@ -0,0 +1,271 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// An `AVX` `YMM` register value.
+///
+/// The low 128 bits are the corresponding `XMM` register.
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct AvxYmmRegisterValue
+{
+	/// Register value (little endian).
+	pub register_value: [u8; 32],
+}
+
+impl Debug for AvxYmmRegisterValue
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_struct("AvxYmmRegisterValue").field("f32x8", &self.f32x8()).finish()
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<__m256> for AvxYmmRegisterValue
+{
+	#[inline(always)]
+	fn from(value: __m256) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<AvxYmmRegisterValue> for __m256
+{
+	#[inline(always)]
+	fn from(value: AvxYmmRegisterValue) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<__m256i> for AvxYmmRegisterValue
+{
+	#[inline(always)]
+	fn from(value: __m256i) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<AvxYmmRegisterValue> for __m256i
+{
+	#[inline(always)]
+	fn from(value: AvxYmmRegisterValue) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<__m256d> for AvxYmmRegisterValue
+{
+	#[inline(always)]
+	fn from(value: __m256d) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<AvxYmmRegisterValue> for __m256d
+{
+	#[inline(always)]
+	fn from(value: AvxYmmRegisterValue) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+impl AvxYmmRegisterValue
+{
+	/// Low 128 bits (the `XMM` register).
+	#[inline(always)]
+	pub fn low_128_bits(&self) -> SseXmmRegisterValue
+	{
+		SseXmmRegisterValue
+		{
+			register_value: *array_ref!(self.register_value, 0, 16),
+		}
+	}
+
+	/// High 128 bits.
+	#[inline(always)]
+	pub fn high_128_bits(&self) -> &[u8; 16]
+	{
+		array_ref!(self.register_value, 16, 16)
+	}
+
+	/// Lanes as 16-bit IEEE 754 half precision floating point (`f16`) values (`f16x16`).
+	#[inline(always)]
+	pub fn f16x16(&self) -> [Float16; 16]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from 16-bit IEEE 754 half precision floating point (`f16`) values (`f16x16`).
+	#[inline(always)]
+	pub fn set_f16x16(&mut self, lanes: [Float16; 16])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as 16-bit brain floating point (`bf16`) values (`bf16x16`).
+	#[inline(always)]
+	pub fn bf16x16(&self) -> [BFloat16; 16]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from 16-bit brain floating point (`bf16`) values (`bf16x16`).
+	#[inline(always)]
+	pub fn set_bf16x16(&mut self, lanes: [BFloat16; 16])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `f32` values (`f32x8`).
+	#[inline(always)]
+	pub fn f32x8(&self) -> [f32; 8]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `f32` values (`f32x8`).
+	#[inline(always)]
+	pub fn set_f32x8(&mut self, lanes: [f32; 8])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `f64` values (`f64x4`).
+	#[inline(always)]
+	pub fn f64x4(&self) -> [f64; 4]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `f64` values (`f64x4`).
+	#[inline(always)]
+	pub fn set_f64x4(&mut self, lanes: [f64; 4])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `i8` values (`i8x32`).
+	#[inline(always)]
+	pub fn i8x32(&self) -> [i8; 32]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `i8` values (`i8x32`).
+	#[inline(always)]
+	pub fn set_i8x32(&mut self, lanes: [i8; 32])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `i16` values (`i16x16`).
+	#[inline(always)]
+	pub fn i16x16(&self) -> [i16; 16]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `i16` values (`i16x16`).
+	#[inline(always)]
+	pub fn set_i16x16(&mut self, lanes: [i16; 16])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `i32` values (`i32x8`).
+	#[inline(always)]
+	pub fn i32x8(&self) -> [i32; 8]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `i32` values (`i32x8`).
+	#[inline(always)]
+	pub fn set_i32x8(&mut self, lanes: [i32; 8])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `i64` values (`i64x4`).
+	#[inline(always)]
+	pub fn i64x4(&self) -> [i64; 4]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `i64` values (`i64x4`).
+	#[inline(always)]
+	pub fn set_i64x4(&mut self, lanes: [i64; 4])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `u8` values (`u8x32`).
+	#[inline(always)]
+	pub fn u8x32(&self) -> [u8; 32]
+	{
+		self.register_value
+	}
+
+	/// Set lanes from `u8` values (`u8x32`).
+	#[inline(always)]
+	pub fn set_u8x32(&mut self, lanes: [u8; 32])
+	{
+		self.register_value = lanes
+	}
+
+	/// Lanes as `u16` values (`u16x16`).
+	#[inline(always)]
+	pub fn u16x16(&self) -> [u16; 16]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `u16` values (`u16x16`).
+	#[inline(always)]
+	pub fn set_u16x16(&mut self, lanes: [u16; 16])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `u32` values (`u32x8`).
+	#[inline(always)]
+	pub fn u32x8(&self) -> [u32; 8]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `u32` values (`u32x8`).
+	#[inline(always)]
+	pub fn set_u32x8(&mut self, lanes: [u32; 8])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `u64` values (`u64x4`).
+	#[inline(always)]
+	pub fn u64x4(&self) -> [u64; 4]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `u64` values (`u64x4`).
+	#[inline(always)]
+	pub fn set_u64x4(&mut self, lanes: [u64; 4])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+}
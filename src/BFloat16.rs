@@ -0,0 +1,78 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// A 16-bit brain floating point (`bfloat16`) value.
+///
+/// This is the upper 16 bits of an IEEE 754 single precision (`f32`) value: 1 sign bit, 8 exponent bits and 7 fraction bits.
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct BFloat16(u16);
+
+impl Debug for BFloat16
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(&self.to_f32(), f)
+	}
+}
+
+impl From<BFloat16> for f32
+{
+	#[inline(always)]
+	fn from(value: BFloat16) -> Self
+	{
+		value.to_f32()
+	}
+}
+
+impl From<f32> for BFloat16
+{
+	#[inline(always)]
+	fn from(value: f32) -> Self
+	{
+		Self::from_f32(value)
+	}
+}
+
+impl BFloat16
+{
+	/// From raw bits.
+	#[inline(always)]
+	pub const fn from_bits(bits: u16) -> Self
+	{
+		Self(bits)
+	}
+
+	/// To raw bits.
+	#[inline(always)]
+	pub const fn to_bits(self) -> u16
+	{
+		self.0
+	}
+
+	/// Converts to a `f32`; this is always exact.
+	#[inline(always)]
+	pub fn to_f32(self) -> f32
+	{
+		f32::from_bits((self.0 as u32) << 16)
+	}
+
+	/// Converts from a `f32`, rounding to nearest (even).
+	///
+	/// `NaN`s are preserved as quiet `NaN`s.
+	#[inline(always)]
+	pub fn from_f32(value: f32) -> Self
+	{
+		let bits = value.to_bits();
+
+		if value.is_nan()
+		{
+			return Self(((bits >> 16) as u16) | 0x0040)
+		}
+
+		let round_to_nearest_even_bias = 0x7FFF + ((bits >> 16) & 0b1);
+		Self((bits.wrapping_add(round_to_nearest_even_bias) >> 16) as u16)
+	}
+}
@@ -0,0 +1,113 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// A view of `x87`, `SSE` and `AVX` state captured from a 32-bit process, such as one running in compatibility mode under a 64-bit Operating System.
+///
+/// Only the registers a 32-bit process can use are exposed: `XMM0`-`XMM7` and `YMM0`-`YMM7`; the remaining registers are neither restored (read) or written to outside of 64-bit mode.
+///
+/// The `x87` instruction and data pointers are interpreted for the variant of instruction that saved the area; this is normally `X87SaveVariant::FXSAVE` or `X87SaveVariant::XSAVE`, giving 32-bit offsets with 16-bit selectors.
+#[derive(Copy, Clone)]
+pub struct CompatibilityModeState<'a>
+{
+	legacy_region: &'a FXSaveArea,
+	xsave_area_layout: Option<&'a XSaveAreaLayout>,
+	save_variant: X87SaveVariant,
+}
+
+impl<'a> CompatibilityModeState<'a>
+{
+	/// Number of `XMM` and `YMM` registers outside of 64-bit mode.
+	pub const NumberOfRegisters: u8 = 8;
+
+	/// From a `FXSAVE` area saved by `save_variant`.
+	#[inline(always)]
+	pub fn from_fxsave_area(fxsave_area: &'a FXSaveArea, save_variant: X87SaveVariant) -> Self
+	{
+		Self
+		{
+			legacy_region: fxsave_area,
+			xsave_area_layout: None,
+			save_variant,
+		}
+	}
+
+	/// From a `XSAVE` area saved by `save_variant`.
+	#[inline(always)]
+	pub fn from_xsave_area_layout(xsave_area_layout: &'a XSaveAreaLayout, save_variant: X87SaveVariant) -> Self
+	{
+		Self
+		{
+			legacy_region: &xsave_area_layout.legacy_region,
+			xsave_area_layout: Some(xsave_area_layout),
+			save_variant,
+		}
+	}
+
+	/// The variant of instruction that saved the area.
+	#[inline(always)]
+	pub fn save_variant(&self) -> X87SaveVariant
+	{
+		self.save_variant
+	}
+
+	/// `x87` state, or `None` if not present.
+	#[inline(always)]
+	pub fn x87_state(&self) -> Option<(&'a X87StatePart1, &'a X87StatePart2)>
+	{
+		match self.xsave_area_layout
+		{
+			None => Some(self.legacy_region.x87_state()),
+			Some(xsave_area_layout) => xsave_area_layout.x87_state(),
+		}
+	}
+
+	/// The `x87` instruction and data pointers, including selectors unless the processor deprecates them; see `X87StatePart1::fpu_pointers()`.
+	///
+	/// Returns `None` if the `x87` state is not present or the pointers were not saved.
+	#[inline(always)]
+	pub fn fpu_pointers(&self, extended_state_information: &ExtendedStateInformation) -> Option<FpuPointers>
+	{
+		let save_variant = self.save_variant;
+		self.x87_state().and_then(|(x87_state_part_1, _)| x87_state_part_1.fpu_pointers(save_variant, extended_state_information))
+	}
+
+	/// `MXCSR` register value, or `None` if not present.
+	#[inline(always)]
+	pub fn mxcsr_register_value(&self) -> Option<MxcsrRegisterValue>
+	{
+		self.sse_state().map(|(sse_state_part_1, _)| sse_state_part_1.mxcsr_register_value)
+	}
+
+	/// Stored value of SSE XMM register `XMMn`, or `None` if the `SSE` state is not present.
+	///
+	/// `n` must be less than 8.
+	#[inline(always)]
+	pub fn xmm(&self, n: u8) -> Option<&'a SseXmmRegisterValue>
+	{
+		assert!(n < Self::NumberOfRegisters, "n `{}` is not less than 8", n);
+
+		self.sse_state().map(|(_, sse_state_part_2)| sse_state_part_2.xmm(n))
+	}
+
+	/// Full 256-bit value of the `AVX` register `YMMn`, or `None` if this is a `FXSAVE` area; see `XSaveAreaLayout::ymm()`.
+	///
+	/// `n` must be less than 8.
+	#[inline(always)]
+	pub fn ymm(&self, n: u8) -> Option<AvxYmmRegisterValue>
+	{
+		assert!(n < Self::NumberOfRegisters, "n `{}` is not less than 8", n);
+
+		self.xsave_area_layout.map(|xsave_area_layout| xsave_area_layout.ymm(n))
+	}
+
+	#[inline(always)]
+	fn sse_state(&self) -> Option<(&'a SseStatePart1, &'a SseStatePart2)>
+	{
+		match self.xsave_area_layout
+		{
+			None => Some(self.legacy_region.sse_state()),
+			Some(xsave_area_layout) => xsave_area_layout.sse_state(),
+		}
+	}
+}
@@ -52,6 +52,31 @@ pub struct ExtendedStateInformation
 	///
 	/// If this is `true` then `has_xsavec_feature` is also `true`.
 	pub has_xsaves_feature: bool,
+
+	/// Processor vendor.
+	pub processor_vendor: ProcessorVendor,
+
+	/// Has AMD Lightweight Profiling (`lwp` feature).
+	///
+	/// Taken from bit 15 of `ECX` returned by `CPUID` leaf `0x8000_0001`; always `false` for processors that are not AMD-like.
+	pub has_lightweight_profiling_feature: bool,
+
+	/// AMD processor always saves the `x87` error pointers (`xsaveerptr` feature).
+	///
+	/// Taken from bit 2 of `EBX` returned by `CPUID` leaf `0x8000_0008`; always `false` for processors that are not AMD-like.
+	///
+	/// If `true`, `FXSAVE`, `XSAVE`, `XSAVEOPT`, `XSAVEC` and `XSAVES` always save the `FIP`, `FDP` and `FOP` fields.
+	pub always_saves_fpu_error_pointers: bool,
+
+	/// The `x87` data pointer, `FDP`, is only updated by `x87` non-control instructions that incur an unmasked exception (`FDP_EXCPTN_ONLY`).
+	///
+	/// Taken from bit 6 of `EBX` returned by `CPUID` leaf `0x07`, sub-leaf `0`.
+	pub fpu_data_pointer_only_updated_on_x87_exceptions: bool,
+
+	/// The `x87` selectors, `FCS` and `FDS`, are deprecated and saved as `0x0000`.
+	///
+	/// Taken from bit 13 of `EBX` returned by `CPUID` leaf `0x07`, sub-leaf `0`.
+	pub fpu_cs_and_fpu_ds_are_deprecated: bool,
 }
 
 impl ExtendedStateInformation
@@ -67,7 +92,24 @@ impl ExtendedStateInformation
 	#[inline(always)]
 	pub fn supervisor_state_component_possible(&self, supervisor_state_component: SupervisorStateComponent) -> bool
 	{
-		self.xsave_state_components_present.is_present(supervisor_state_component.to_state_component())
+		self.xsaves_state_components_present.is_present(supervisor_state_component.to_state_component())
+	}
+
+	/// Is a state component, known or unknown, present in either `XCR0` (user) or `IA32_XSS` (supervisor)?
+	#[inline(always)]
+	pub fn state_component_possible(&self, state_component: StateComponent) -> bool
+	{
+		self.xsave_state_components_present.is_present(state_component) || self.xsaves_state_components_present.is_present(state_component)
+	}
+
+	/// Are the `fpu_instruction_pointer_offset`, `fpu_data_pointer_offset` and `fpu_opcode` fields of `X87StatePart1` meaningful after a save when no unmasked `x87` exception is pending?
+	///
+	/// Older AMD processors only save these fields if the exception summary bit, `ES`, of the `x87` status word is set; otherwise they are saved as zero.
+	/// Intel processors always save them.
+	#[inline(always)]
+	pub fn fpu_error_pointers_are_meaningful_after_non_exception_save(&self) -> bool
+	{
+		!self.processor_vendor.is_amd_like() || self.always_saves_fpu_error_pointers
 	}
 
 	/// Creates a new instance.
@@ -85,6 +127,30 @@ impl ExtendedStateInformation
 				let sub_function_1 = Self::eax_extended_state_information(1).unwrap();
 				let sub_function_1_eax = sub_function_1.eax;
 
+				let processor_vendor = ProcessorVendor::new().unwrap();
+				let (has_lightweight_profiling_feature, always_saves_fpu_error_pointers) = if processor_vendor.is_amd_like()
+				{
+					let has_lightweight_profiling_feature = match Self::extended_function_information(0x8000_0001)
+					{
+						None => false,
+						Some(result) => result.ecx & (1 << 15) != 0,
+					};
+
+					let always_saves_fpu_error_pointers = match Self::extended_function_information(0x8000_0008)
+					{
+						None => false,
+						Some(result) => result.ebx & (1 << 2) != 0,
+					};
+
+					(has_lightweight_profiling_feature, always_saves_fpu_error_pointers)
+				}
+				else
+				{
+					(false, false)
+				};
+
+				let structured_extended_feature_flags_ebx = Self::structured_extended_feature_flags_ebx();
+
 				Some
 				(
 					Self
@@ -106,6 +172,16 @@ impl ExtendedStateInformation
 						xgetbv_supports_ecx_equals_one: sub_function_1_eax & 0b0100 != 0,
 
 						has_xsaves_feature: sub_function_1_eax & 0b1000 != 0,
+
+						processor_vendor,
+
+						has_lightweight_profiling_feature,
+
+						always_saves_fpu_error_pointers,
+
+						fpu_data_pointer_only_updated_on_x87_exceptions: structured_extended_feature_flags_ebx & (1 << 6) != 0,
+
+						fpu_cs_and_fpu_ds_are_deprecated: structured_extended_feature_flags_ebx & (1 << 13) != 0,
 					}
 				)
 			}
@@ -131,6 +207,38 @@ impl ExtendedStateInformation
 		}
 	}
 
+	#[inline(always)]
+	fn structured_extended_feature_flags_ebx() -> u32
+	{
+		const EAX_STRUCTURED_EXTENDED_FEATURE_FLAGS: u32 = 0x07;
+
+		let maximum_eax_value = unsafe { __cpuid(0x00) }.eax;
+		if EAX_STRUCTURED_EXTENDED_FEATURE_FLAGS <= maximum_eax_value
+		{
+			unsafe { __cpuid_count(EAX_STRUCTURED_EXTENDED_FEATURE_FLAGS, 0) }.ebx
+		}
+		else
+		{
+			0
+		}
+	}
+
+	#[inline(always)]
+	fn extended_function_information(eax_or_leaf: u32) -> Option<CpuidResult>
+	{
+		const EAX_EXTENDED_FUNCTION_INFO: u32 = 0x8000_0000;
+
+		let maximum_eax_value = unsafe { __cpuid(EAX_EXTENDED_FUNCTION_INFO) }.eax;
+		if eax_or_leaf <= maximum_eax_value
+		{
+			Some(unsafe { __cpuid(eax_or_leaf) })
+		}
+		else
+		{
+			None
+		}
+	}
+
 	#[inline(always)]
 	fn is_extended_functionality_type_supported(eax_or_leaf: u32) -> bool
 	{
@@ -0,0 +1,155 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// A 16-bit IEEE 754 half precision floating point (`binary16`) value.
+///
+/// 1 sign bit, 5 exponent bits and 10 fraction bits.
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Float16(u16);
+
+impl Debug for Float16
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(&self.to_f32(), f)
+	}
+}
+
+impl From<Float16> for f32
+{
+	#[inline(always)]
+	fn from(value: Float16) -> Self
+	{
+		value.to_f32()
+	}
+}
+
+impl From<f32> for Float16
+{
+	#[inline(always)]
+	fn from(value: f32) -> Self
+	{
+		Self::from_f32(value)
+	}
+}
+
+impl Float16
+{
+	const SignBit: u16 = 0x8000;
+
+	const ExponentBits: u16 = 0x7C00;
+
+	/// From raw bits.
+	#[inline(always)]
+	pub const fn from_bits(bits: u16) -> Self
+	{
+		Self(bits)
+	}
+
+	/// To raw bits.
+	#[inline(always)]
+	pub const fn to_bits(self) -> u16
+	{
+		self.0
+	}
+
+	/// Converts to a `f32`; this is always exact.
+	#[inline]
+	pub fn to_f32(self) -> f32
+	{
+		let bits = self.0 as u32;
+		let sign = (bits & (Self::SignBit as u32)) << 16;
+		let exponent = (bits >> 10) & 0b1_1111;
+		let fraction = bits & 0b11_1111_1111;
+
+		match exponent
+		{
+			0b1_1111 => f32::from_bits(sign | 0x7F80_0000 | (fraction << 13)),
+
+			0 =>
+			{
+				// Zero or subnormal; the unit in the last place is 2^-24.
+				let magnitude = (fraction as f32) / ((1 << 24) as f32);
+				if sign == 0
+				{
+					magnitude
+				}
+				else
+				{
+					-magnitude
+				}
+			}
+
+			_ => f32::from_bits(sign | ((exponent + 127 - 15) << 23) | (fraction << 13)),
+		}
+	}
+
+	/// Converts from a `f32`, rounding to nearest (even).
+	///
+	/// Values too large become infinities; `NaN`s are preserved as quiet `NaN`s.
+	#[inline]
+	pub fn from_f32(value: f32) -> Self
+	{
+		let bits = value.to_bits();
+		let sign = ((bits >> 16) as u16) & Self::SignBit;
+		let exponent = ((bits >> 23) & 0xFF) as i32;
+		let fraction = bits & 0x7F_FFFF;
+
+		if exponent == 0xFF
+		{
+			let quiet_nan = if fraction == 0
+			{
+				0
+			}
+			else
+			{
+				0x0200 | ((fraction >> 13) as u16)
+			};
+			return Self(sign | Self::ExponentBits | quiet_nan)
+		}
+
+		let unbiased_exponent = exponent - 127;
+
+		if unbiased_exponent > 15
+		{
+			return Self(sign | Self::ExponentBits)
+		}
+
+		#[inline(always)]
+		fn round_to_nearest_even(value: u32, discarded_bits: u32) -> u32
+		{
+			let truncated = value >> discarded_bits;
+			let remainder = value & ((1 << discarded_bits) - 1);
+			let halfway = 1 << (discarded_bits - 1);
+			if remainder > halfway || (remainder == halfway && truncated & 0b1 == 1)
+			{
+				truncated + 1
+			}
+			else
+			{
+				truncated
+			}
+		}
+
+		if unbiased_exponent >= -14
+		{
+			// A carry out of the fraction correctly increments the exponent (and may produce infinity).
+			let biased_exponent = (unbiased_exponent + 15) as u32;
+			let rounded = round_to_nearest_even((biased_exponent << 23) | fraction, 13);
+			return Self(sign | (rounded as u16))
+		}
+
+		if unbiased_exponent < -25
+		{
+			return Self(sign)
+		}
+
+		// Subnormal; a carry out of the fraction correctly produces the smallest normal.
+		let significand = fraction | 0x80_0000;
+		let discarded_bits = (-1 - unbiased_exponent) as u32;
+		Self(sign | (round_to_nearest_even(significand, discarded_bits) as u16))
+	}
+}
@@ -9,12 +9,94 @@
 /// *  rounding control field for SIMD floating-point operations;
 /// * flush-to-zero flag that provides a means of controlling underflow conditions on SIMD floating-point operations;
 /// * denormals-are-zeros flag that controls how SIMD floating-point instructions handle denormal source operands
-#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct MxcsrRegisterValue(u32);
 
 impl MxcsrRegisterValue
 {
+	/// The initial value, `0x1F80`, after power-up, reset or `INIT`: all exceptions masked, round to nearest, and flush-to-zero and denormals-are-zeros disabled.
+	pub const Initial: Self = MxcsrRegisterValue(0x1F80);
+
+	/// The `MXCSR` register mask to assume if the `mxcsr_register_mask` field of a `FXSAVE` area is zero, as it is on older processors.
+	///
+	/// All bits other than `DAZ` (bit 6) and bits 16 to 31 inclusive are supported.
+	///
+	/// See Section 11.6.6 (Guidelines for Writing to the MXCSR Register) of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
+	pub const DefaultMxcsrRegisterMask: u32 = 0x0000_FFBF;
+
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u32) -> Self
+	{
+		MxcsrRegisterValue(value)
+	}
+
+	/// Raw value.
+	#[inline(always)]
+	pub fn value(self) -> u32
+	{
+		self.0
+	}
+
+	/// The `MXCSR` register mask of the current processor, found by doing a `FXSAVE`.
+	///
+	/// Returns `DefaultMxcsrRegisterMask` if the processor saves the `mxcsr_register_mask` field as zero.
+	///
+	/// As this does a `FXSAVE`, call it once and pass the result to `validate_and_restore_current_value_in_register()` and `FXSaveArea::validate_mxcsr_register_value()`.
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "fxsr"))]
+	#[inline(always)]
+	pub fn mxcsr_register_mask_of_current_processor() -> u32
+	{
+		Self::effective_mxcsr_register_mask(FXSaveArea::save().0.sse_state_part_1.mxcsr_register_mask)
+	}
+
+	/// The `MXCSR` register mask to validate against, given the `mxcsr_register_mask` field of a `FXSAVE` area: `DefaultMxcsrRegisterMask` if zero, otherwise `mxcsr_register_mask`.
+	#[inline(always)]
+	pub fn effective_mxcsr_register_mask(mxcsr_register_mask: u32) -> u32
+	{
+		if mxcsr_register_mask == 0
+		{
+			Self::DefaultMxcsrRegisterMask
+		}
+		else
+		{
+			mxcsr_register_mask
+		}
+	}
+
+	/// Validates that no bits are set which are reserved according to `mxcsr_register_mask` (itself taken from the `mxcsr_register_mask` field of a `FXSAVE` area, and so interpreted as by `effective_mxcsr_register_mask()`).
+	///
+	/// Loading a value with reserved bits set, by restoring it or by `FXRSTOR` or `XRSTOR`, causes a general-protection fault (`#GP`).
+	/// Of note, the `DAZ` bit is reserved on some older processors.
+	///
+	/// Returns the reserved bits that are set as an error.
+	#[inline(always)]
+	pub fn validate(self, mxcsr_register_mask: u32) -> Result<(), u32>
+	{
+		let reserved_bits_set = self.0 & !Self::effective_mxcsr_register_mask(mxcsr_register_mask);
+		if reserved_bits_set == 0
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(reserved_bits_set)
+		}
+	}
+
+	/// Validates against `mxcsr_register_mask_of_current_processor` (see `validate()` and `mxcsr_register_mask_of_current_processor()`) and, if valid, sets the current value in the register.
+	///
+	/// Only affects the current thread.
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))]
+	#[inline(always)]
+	pub fn validate_and_restore_current_value_in_register(self, mxcsr_register_mask_of_current_processor: u32) -> Result<(), u32>
+	{
+		self.validate(mxcsr_register_mask_of_current_processor)?;
+		self.restore_current_value_in_register();
+		Ok(())
+	}
+
 	/// Get current value in register.
 	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))]
 	#[inline(always)]
@@ -48,6 +130,13 @@ impl MxcsrRegisterValue
 		self.bit_set(6)
 	}
 
+	/// Sets Denormals Are Zeros, `DAZ`.
+	#[inline(always)]
+	pub fn set_denormals_are_zeros(&mut self, value: bool)
+	{
+		self.set_bit(6, value)
+	}
+
 	/// Flag bits 0 to 5 inclusive.
 	///
 	/// A 6-bit (`u6`) value.
@@ -57,6 +146,17 @@ impl MxcsrRegisterValue
 		(self.0 & 0b0011_1111) as u8
 	}
 
+	/// Sets flag bits 0 to 5 inclusive.
+	///
+	/// `flag_bits` must be a 6-bit (`u6`) value.
+	#[inline(always)]
+	pub fn set_flag_bits(&mut self, flag_bits: u8)
+	{
+		assert!(flag_bits < 0b0100_0000, "flag_bits `{}` is not a 6-bit value", flag_bits);
+
+		self.0 = (self.0 & !0b0011_1111) | (flag_bits as u32)
+	}
+
 	/// Invalid Operation exception flag, `IE`.
 	#[inline(always)]
 	pub fn invalid_operation_flag(self) -> bool
@@ -64,6 +164,13 @@ impl MxcsrRegisterValue
 		self.bit_set(0)
 	}
 
+	/// Sets Invalid Operation exception flag, `IE`.
+	#[inline(always)]
+	pub fn set_invalid_operation_flag(&mut self, value: bool)
+	{
+		self.set_bit(0, value)
+	}
+
 	/// Denormal exception flag, `DE`.
 	#[inline(always)]
 	pub fn denormal_flag(self) -> bool
@@ -71,6 +178,13 @@ impl MxcsrRegisterValue
 		self.bit_set(1)
 	}
 
+	/// Sets Denormal exception flag, `DE`.
+	#[inline(always)]
+	pub fn set_denormal_flag(&mut self, value: bool)
+	{
+		self.set_bit(1, value)
+	}
+
 	/// Divide-by-Zero exception flag, `ZE`.
 	#[inline(always)]
 	pub fn divide_by_zero_flag(self) -> bool
@@ -78,6 +192,13 @@ impl MxcsrRegisterValue
 		self.bit_set(2)
 	}
 
+	/// Sets Divide-by-Zero exception flag, `ZE`.
+	#[inline(always)]
+	pub fn set_divide_by_zero_flag(&mut self, value: bool)
+	{
+		self.set_bit(2, value)
+	}
+
 	/// Overflow exception flag, `OE`.
 	#[inline(always)]
 	pub fn overflow_flag(self) -> bool
@@ -85,6 +206,13 @@ impl MxcsrRegisterValue
 		self.bit_set(3)
 	}
 
+	/// Sets Overflow exception flag, `OE`.
+	#[inline(always)]
+	pub fn set_overflow_flag(&mut self, value: bool)
+	{
+		self.set_bit(3, value)
+	}
+
 	/// Underflow exception flag, `UE`.
 	#[inline(always)]
 	pub fn underflow_flag(self) -> bool
@@ -92,6 +220,13 @@ impl MxcsrRegisterValue
 		self.bit_set(4)
 	}
 
+	/// Sets Underflow exception flag, `UE`.
+	#[inline(always)]
+	pub fn set_underflow_flag(&mut self, value: bool)
+	{
+		self.set_bit(4, value)
+	}
+
 	/// Precision exception flag, `PE`.
 	#[inline(always)]
 	pub fn precision_flag(self) -> bool
@@ -99,7 +234,14 @@ impl MxcsrRegisterValue
 		self.bit_set(5)
 	}
 
-	/// Flag bits 0 to 5 inclusive.
+	/// Sets Precision exception flag, `PE`.
+	#[inline(always)]
+	pub fn set_precision_flag(&mut self, value: bool)
+	{
+		self.set_bit(5, value)
+	}
+
+	/// Mask bits 7 to 12 inclusive.
 	///
 	/// A 6-bit (`u6`) value.
 	#[inline(always)]
@@ -108,6 +250,17 @@ impl MxcsrRegisterValue
 		((self.0 & 0b00001_1111_1000_0000) >> 7) as u8
 	}
 
+	/// Sets mask bits 7 to 12 inclusive.
+	///
+	/// `mask_bits` must be a 6-bit (`u6`) value.
+	#[inline(always)]
+	pub fn set_mask_bits(&mut self, mask_bits: u8)
+	{
+		assert!(mask_bits < 0b0100_0000, "mask_bits `{}` is not a 6-bit value", mask_bits);
+
+		self.0 = (self.0 & !0b00001_1111_1000_0000) | ((mask_bits as u32) << 7)
+	}
+
 	/// Invalid Operation exception mask bit, `IM`.
 	#[inline(always)]
 	pub fn invalid_operation_mask_bit(self) -> bool
@@ -115,6 +268,13 @@ impl MxcsrRegisterValue
 		self.bit_set(7)
 	}
 
+	/// Sets Invalid Operation exception mask bit, `IM`.
+	#[inline(always)]
+	pub fn set_invalid_operation_mask_bit(&mut self, value: bool)
+	{
+		self.set_bit(7, value)
+	}
+
 	/// Denormal exception mask bit, `DM`.
 	#[inline(always)]
 	pub fn denormal_mask_bit(self) -> bool
@@ -122,6 +282,13 @@ impl MxcsrRegisterValue
 		self.bit_set(8)
 	}
 
+	/// Sets Denormal exception mask bit, `DM`.
+	#[inline(always)]
+	pub fn set_denormal_mask_bit(&mut self, value: bool)
+	{
+		self.set_bit(8, value)
+	}
+
 	/// Divide-by-Zero exception mask bit, `ZM`.
 	#[inline(always)]
 	pub fn divide_by_zero_mask_bit(self) -> bool
@@ -129,6 +296,13 @@ impl MxcsrRegisterValue
 		self.bit_set(9)
 	}
 
+	/// Sets Divide-by-Zero exception mask bit, `ZM`.
+	#[inline(always)]
+	pub fn set_divide_by_zero_mask_bit(&mut self, value: bool)
+	{
+		self.set_bit(9, value)
+	}
+
 	/// Overflow exception mask bit, `OM`.
 	#[inline(always)]
 	pub fn overflow_mask_bit(self) -> bool
@@ -136,6 +310,13 @@ impl MxcsrRegisterValue
 		self.bit_set(10)
 	}
 
+	/// Sets Overflow exception mask bit, `OM`.
+	#[inline(always)]
+	pub fn set_overflow_mask_bit(&mut self, value: bool)
+	{
+		self.set_bit(10, value)
+	}
+
 	/// Underflow exception mask bit, `UM`.
 	#[inline(always)]
 	pub fn underflow_mask_bit(self) -> bool
@@ -143,6 +324,13 @@ impl MxcsrRegisterValue
 		self.bit_set(11)
 	}
 
+	/// Sets Underflow exception mask bit, `UM`.
+	#[inline(always)]
+	pub fn set_underflow_mask_bit(&mut self, value: bool)
+	{
+		self.set_bit(11, value)
+	}
+
 	/// Precision exception mask bit, `PM`.
 	#[inline(always)]
 	pub fn precision_mask_bit(self) -> bool
@@ -150,23 +338,57 @@ impl MxcsrRegisterValue
 		self.bit_set(12)
 	}
 
+	/// Sets Precision exception mask bit, `PM`.
+	#[inline(always)]
+	pub fn set_precision_mask_bit(&mut self, value: bool)
+	{
+		self.set_bit(12, value)
+	}
+
 	/// Rounding control, `RC`.
 	#[inline(always)]
 	pub fn rounding_control(self) -> RoundingControl
 	{
-		unsafe { transmute((self.0 >> 13) as u8) }
+		unsafe { transmute(((self.0 >> 13) & 0b11) as u8) }
 	}
 
-	/// Precision exception mask bit, `PM`.
+	/// Sets rounding control, `RC`.
+	#[inline(always)]
+	pub fn set_rounding_control(&mut self, rounding_control: RoundingControl)
+	{
+		self.0 = (self.0 & !(0b11 << 13)) | ((rounding_control as u32) << 13)
+	}
+
+	/// Flush To Zero, `FTZ`.
 	#[inline(always)]
 	pub fn flush_to_zero(self) -> bool
 	{
 		self.bit_set(15)
 	}
 
+	/// Sets Flush To Zero, `FTZ`.
+	#[inline(always)]
+	pub fn set_flush_to_zero(&mut self, value: bool)
+	{
+		self.set_bit(15, value)
+	}
+
 	#[inline(always)]
 	fn bit_set(self, bit: u32) -> bool
 	{
 		self.0 & (1 << bit) != 0
 	}
+
+	#[inline(always)]
+	fn set_bit(&mut self, bit: u32, value: bool)
+	{
+		if value
+		{
+			self.0 |= 1 << bit
+		}
+		else
+		{
+			self.0 &= !(1 << bit)
+		}
+	}
 }
@@ -0,0 +1,25 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Operations only allowed for kernel-mode code (current privilege level 0, `CPL0`).
+///
+/// Abstracted so that code not running in kernel-mode can forward them (eg to a hypervisor or a kernel driver) and so that they can be intercepted.
+/// Code running in kernel mode can use `Ring0PrivilegedOperations`.
+pub trait PrivilegedOperations
+{
+	/// Reads the model-specific register (`MSR`) at address `model_specific_register`.
+	///
+	/// # Safety
+	///
+	/// `model_specific_register` must be a valid `MSR` address for the current processor; otherwise, in kernel mode, a general-protection fault (`#GP`) occurs.
+	unsafe fn read_model_specific_register(&self, model_specific_register: u32) -> u64;
+
+	/// Writes `value` to the model-specific register (`MSR`) at address `model_specific_register`.
+	///
+	/// # Safety
+	///
+	/// `model_specific_register` must be a valid `MSR` address for the current processor and `value` must not set any reserved bits; otherwise, in kernel mode, a general-protection fault (`#GP`) occurs.
+	/// Writing a `MSR` can change the behaviour of the processor in ways that invalidate assumptions made by other code.
+	unsafe fn write_model_specific_register(&self, model_specific_register: u32, value: u64);
+}
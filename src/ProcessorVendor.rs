@@ -0,0 +1,67 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Processor vendor, as identified by `CPUID` leaf `0x00`.
+///
+/// Some `XSAVE` and `FXSAVE` behaviours, such as when the `x87` error pointers are saved, differ between vendors.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProcessorVendor
+{
+	/// `GenuineIntel`.
+	Intel,
+
+	/// `AuthenticAMD`.
+	Amd,
+
+	/// `HygonGenuine`.
+	///
+	/// Behaves as `Amd`.
+	Hygon,
+
+	/// Any other vendor identification string.
+	Other([u8; 12]),
+}
+
+impl ProcessorVendor
+{
+	/// Identifies the processor vendor.
+	///
+	/// Returns `None` if `CPUID` is not supported.
+	#[inline(always)]
+	pub fn new() -> Option<Self>
+	{
+		if !has_cpuid()
+		{
+			return None
+		}
+
+		let result = unsafe { __cpuid(0x00) };
+
+		let mut vendor_identification = [0u8; 12];
+		vendor_identification[0 .. 4].copy_from_slice(&result.ebx.to_le_bytes());
+		vendor_identification[4 .. 8].copy_from_slice(&result.edx.to_le_bytes());
+		vendor_identification[8 .. 12].copy_from_slice(&result.ecx.to_le_bytes());
+
+		use self::ProcessorVendor::*;
+		Some
+		(
+			match &vendor_identification
+			{
+				b"GenuineIntel" => Intel,
+				b"AuthenticAMD" => Amd,
+				b"HygonGenuine" => Hygon,
+				_ => Other(vendor_identification),
+			}
+		)
+	}
+
+	/// Does this processor behave as an AMD processor?
+	#[inline(always)]
+	pub fn is_amd_like(self) -> bool
+	{
+		use self::ProcessorVendor::*;
+
+		matches!(self, Amd | Hygon)
+	}
+}
@@ -0,0 +1,66 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Privileged operations executed directly, for code running in kernel mode (current privilege level 0, `CPL0`).
+///
+/// Use in other modes will cause a general-protection fault (`#GP`).
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ring0PrivilegedOperations;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl PrivilegedOperations for Ring0PrivilegedOperations
+{
+	/// Uses the `RDMSR` instruction; see <https://github.com/HJLebbink/asm-dude/wiki/RDMSR>.
+	#[inline(always)]
+	unsafe fn read_model_specific_register(&self, model_specific_register: u32) -> u64
+	{
+		let mut low: u32 = uninitialized();
+		let mut high: u32 = uninitialized();
+
+		asm!
+		(
+			"rdmsr"
+			:
+				// Output constraints.
+				"={eax}"(low),
+				"={edx}"(high)
+			:
+				// Input constraints.
+				"{ecx}"(model_specific_register)
+			:
+				// Clobbers.
+			:
+				// Options.
+				"volatile"
+		);
+
+		((high as u64) << 32) | (low as u64)
+	}
+
+	/// Uses the `WRMSR` instruction; see <https://github.com/HJLebbink/asm-dude/wiki/WRMSR>.
+	#[inline(always)]
+	unsafe fn write_model_specific_register(&self, model_specific_register: u32, value: u64)
+	{
+		let low = value as u32;
+		let high = (value >> 32) as u32;
+
+		asm!
+		(
+			"wrmsr"
+			:
+				// Output constraints.
+			:
+				// Input constraints.
+				"{ecx}"(model_specific_register),
+				"{eax}"(low),
+				"{edx}"(high)
+			:
+				// Clobbers.
+			:
+				// Options.
+				"volatile"
+		);
+	}
+}
@@ -3,10 +3,250 @@
 
 
 /// A `SSE` `XMM` register value.
-#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SseXmmRegisterValue
 {
 	/// Register value (little endian).
 	pub register_value: [u8; 16],
 }
+
+impl Debug for SseXmmRegisterValue
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_struct("SseXmmRegisterValue").field("f32x4", &self.f32x4()).finish()
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<__m128> for SseXmmRegisterValue
+{
+	#[inline(always)]
+	fn from(value: __m128) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<SseXmmRegisterValue> for __m128
+{
+	#[inline(always)]
+	fn from(value: SseXmmRegisterValue) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<__m128i> for SseXmmRegisterValue
+{
+	#[inline(always)]
+	fn from(value: __m128i) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<SseXmmRegisterValue> for __m128i
+{
+	#[inline(always)]
+	fn from(value: SseXmmRegisterValue) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<__m128d> for SseXmmRegisterValue
+{
+	#[inline(always)]
+	fn from(value: __m128d) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl From<SseXmmRegisterValue> for __m128d
+{
+	#[inline(always)]
+	fn from(value: SseXmmRegisterValue) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+impl SseXmmRegisterValue
+{
+	/// Lanes as 16-bit IEEE 754 half precision floating point (`f16`) values (`f16x8`).
+	#[inline(always)]
+	pub fn f16x8(&self) -> [Float16; 8]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from 16-bit IEEE 754 half precision floating point (`f16`) values (`f16x8`).
+	#[inline(always)]
+	pub fn set_f16x8(&mut self, lanes: [Float16; 8])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as 16-bit brain floating point (`bf16`) values (`bf16x8`).
+	#[inline(always)]
+	pub fn bf16x8(&self) -> [BFloat16; 8]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from 16-bit brain floating point (`bf16`) values (`bf16x8`).
+	#[inline(always)]
+	pub fn set_bf16x8(&mut self, lanes: [BFloat16; 8])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `f32` values (`f32x4`).
+	#[inline(always)]
+	pub fn f32x4(&self) -> [f32; 4]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `f32` values (`f32x4`).
+	#[inline(always)]
+	pub fn set_f32x4(&mut self, lanes: [f32; 4])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `f64` values (`f64x2`).
+	#[inline(always)]
+	pub fn f64x2(&self) -> [f64; 2]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `f64` values (`f64x2`).
+	#[inline(always)]
+	pub fn set_f64x2(&mut self, lanes: [f64; 2])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `i8` values (`i8x16`).
+	#[inline(always)]
+	pub fn i8x16(&self) -> [i8; 16]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `i8` values (`i8x16`).
+	#[inline(always)]
+	pub fn set_i8x16(&mut self, lanes: [i8; 16])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `i16` values (`i16x8`).
+	#[inline(always)]
+	pub fn i16x8(&self) -> [i16; 8]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `i16` values (`i16x8`).
+	#[inline(always)]
+	pub fn set_i16x8(&mut self, lanes: [i16; 8])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `i32` values (`i32x4`).
+	#[inline(always)]
+	pub fn i32x4(&self) -> [i32; 4]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `i32` values (`i32x4`).
+	#[inline(always)]
+	pub fn set_i32x4(&mut self, lanes: [i32; 4])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `i64` values (`i64x2`).
+	#[inline(always)]
+	pub fn i64x2(&self) -> [i64; 2]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `i64` values (`i64x2`).
+	#[inline(always)]
+	pub fn set_i64x2(&mut self, lanes: [i64; 2])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `u8` values (`u8x16`).
+	#[inline(always)]
+	pub fn u8x16(&self) -> [u8; 16]
+	{
+		self.register_value
+	}
+
+	/// Set lanes from `u8` values (`u8x16`).
+	#[inline(always)]
+	pub fn set_u8x16(&mut self, lanes: [u8; 16])
+	{
+		self.register_value = lanes
+	}
+
+	/// Lanes as `u16` values (`u16x8`).
+	#[inline(always)]
+	pub fn u16x8(&self) -> [u16; 8]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `u16` values (`u16x8`).
+	#[inline(always)]
+	pub fn set_u16x8(&mut self, lanes: [u16; 8])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `u32` values (`u32x4`).
+	#[inline(always)]
+	pub fn u32x4(&self) -> [u32; 4]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `u32` values (`u32x4`).
+	#[inline(always)]
+	pub fn set_u32x4(&mut self, lanes: [u32; 4])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+
+	/// Lanes as `u64` values (`u64x2`).
+	#[inline(always)]
+	pub fn u64x2(&self) -> [u64; 2]
+	{
+		unsafe { transmute(self.register_value) }
+	}
+
+	/// Set lanes from `u64` values (`u64x2`).
+	#[inline(always)]
+	pub fn set_u64x2(&mut self, lanes: [u64; 2])
+	{
+		self.register_value = unsafe { transmute(lanes) }
+	}
+}
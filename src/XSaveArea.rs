@@ -8,7 +8,9 @@ pub struct XSaveArea<Allocator: Alloc>
 {
 	allocator: Allocator,
 	pointer: NonNull<XSaveAreaLayout>,
-	xsave_area_size_supported_features: usize,
+	capacity: usize,
+	save_variant: X87SaveVariant,
+	required_capacity_cache: Option<((StateComponentBitmap, bool, bool), usize)>,
 }
 
 impl<Allocator: Alloc> Deref for XSaveArea<Allocator>
@@ -37,13 +39,67 @@ impl<Allocator: Alloc> Drop for XSaveArea<Allocator>
 	fn drop(&mut self)
 	{
 		let pointer = unsafe { NonNull::new_unchecked(self.pointer.as_ptr() as *mut XSaveAreaLayout as *mut u8) };
-		let layout = Self::layout(self.xsave_area_size_supported_features);
+		let layout = Self::layout(self.capacity);
 		unsafe { self.allocator.dealloc(pointer, layout) }
 	}
 }
 
 impl<Allocator: Alloc> XSaveArea<Allocator>
 {
+	#[cfg(target_arch = "x86")] const NativeX87SaveVariant: X87SaveVariant = X87SaveVariant::XSAVE;
+	#[cfg(target_arch = "x86_64")] const NativeX87SaveVariant: X87SaveVariant = X87SaveVariant::XSAVE64;
+
+	/// Size of the legacy region and the `XSAVE` header.
+	const MinimumCapacity: usize = 576;
+
+	/// Allocates an empty `XSAVE` area of `capacity` bytes, to be used with `save_into()` and similar.
+	///
+	/// The legacy region and the `XSAVE` header are zeroed, except for the `MXCSR` register value, which is its initial value (`0x1F80`) as `XRSTOR` loads it even if `XSTATE_BV` is zero.
+	/// Restoring an empty area therefore puts all state components in their initial configuration.
+	///
+	/// `capacity` must be at least 576 bytes; it should be `extended_state_information.xsave_area_size_supported_features`, or `extended_state_information.xsaves_area_size_enabled_features` for `save_supervisor_into()`.
+	#[inline(always)]
+	pub fn new_empty(mut allocator: Allocator, capacity: usize) -> Result<Self, AllocErr>
+	{
+		assert!(capacity >= Self::MinimumCapacity, "capacity `{}` is less than 576", capacity);
+
+		let layout = Self::layout(capacity);
+		let pointer = unsafe { NonNull::new_unchecked(allocator.alloc(layout)?.as_ptr() as *mut XSaveAreaLayout) };
+		unsafe { (pointer.as_ptr() as *mut u8).write_bytes(0, Self::MinimumCapacity) };
+
+		let mut this = Self
+		{
+			allocator,
+			pointer,
+			capacity,
+			save_variant: Self::NativeX87SaveVariant,
+			required_capacity_cache: None,
+		};
+		this.legacy_region.sse_state_part_1.mxcsr_register_value = MxcsrRegisterValue::Initial;
+		Ok(this)
+	}
+
+	/// Capacity in bytes.
+	#[inline(always)]
+	pub fn capacity(&self) -> usize
+	{
+		self.capacity
+	}
+
+	/// The variant of instruction that last saved this area; `save_32_bit_format()` and `save_32_bit_format_into()` use `X87SaveVariant::XSAVE`, all others the native variant (`X87SaveVariant::XSAVE64` on x86_64).
+	#[inline(always)]
+	pub fn save_variant(&self) -> X87SaveVariant
+	{
+		self.save_variant
+	}
+
+	/// A view of this area as state captured from a 32-bit process.
+	#[inline(always)]
+	pub fn compatibility_mode_state(&self) -> CompatibilityModeState<'_>
+	{
+		CompatibilityModeState::from_xsave_area_layout(self, self.save_variant)
+	}
+
 	/// Saves a `XSAVE` area into newly allocated memory.
 	///
 	/// Will only work if the Operating System (strictly speaking, code in kernel mode) has set bit 18 in the register `CR4.OSXSAVE`, otherwise usage will cause an  invalid-opcode exception (`#UD`).
@@ -51,13 +107,60 @@ impl<Allocator: Alloc> XSaveArea<Allocator>
 	#[inline(always)]
 	pub fn save(allocator: Allocator, extended_state_information: &ExtendedStateInformation, save_mask: StateComponentBitmap) -> Result<Self, AllocErr>
 	{
-		Self::save_internal(allocator, extended_state_information, save_mask, |pointer, save_mask| unsafe
+		let mut this = Self::new_empty(allocator, extended_state_information.xsave_area_size_supported_features)?;
+		this.save_into(save_mask);
+		Ok(this)
+	}
+
+	/// Saves into this `XSAVE` area, reusing its memory; see `save()`.
+	///
+	/// The `XSTATE_BV` bits of state components not in `save_mask` are left unchanged, so the same `save_mask` should be used each time.
+	///
+	/// Panics if the capacity of this area is insufficient for the state components in `save_mask` that are enabled in `XCR0`.
+	///
+	/// Will only work if the Operating System (strictly speaking, code in kernel mode) has set bit 18 in the register `CR4.OSXSAVE`, otherwise usage will cause an  invalid-opcode exception (`#UD`).
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "xsave"))]
+	#[inline(always)]
+	pub fn save_into(&mut self, save_mask: StateComponentBitmap)
+	{
+		self.save_into_internal(save_mask, Self::NativeX87SaveVariant, false, false, |pointer, save_mask| unsafe
 		{
 			#[cfg(target_arch = "x86")] _xsave(pointer, save_mask);
 			#[cfg(target_arch = "x86_64")] _xsave64(pointer, save_mask);
 		})
 	}
 
+	/// Saves a `XSAVE` area into newly allocated memory using the 32-bit format of `XSAVE` (ie without `REX.W`), even on x86_64.
+	///
+	/// The `x87` instruction and data pointers are saved as 32-bit offsets with 16-bit selectors, as they are for 32-bit processes.
+	///
+	/// Will only work if the Operating System (strictly speaking, code in kernel mode) has set bit 18 in the register `CR4.OSXSAVE`, otherwise usage will cause an  invalid-opcode exception (`#UD`).
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "xsave"))]
+	#[inline(always)]
+	pub fn save_32_bit_format(allocator: Allocator, extended_state_information: &ExtendedStateInformation, save_mask: StateComponentBitmap) -> Result<Self, AllocErr>
+	{
+		let mut this = Self::new_empty(allocator, extended_state_information.xsave_area_size_supported_features)?;
+		this.save_32_bit_format_into(save_mask);
+		Ok(this)
+	}
+
+	/// Saves into this `XSAVE` area, reusing its memory, using the 32-bit format of `XSAVE`; see `save_32_bit_format()`.
+	///
+	/// The `XSTATE_BV` bits of state components not in `save_mask` are left unchanged, so the same `save_mask` should be used each time.
+	///
+	/// Panics if the capacity of this area is insufficient for the state components in `save_mask` that are enabled in `XCR0`.
+	///
+	/// Will only work if the Operating System (strictly speaking, code in kernel mode) has set bit 18 in the register `CR4.OSXSAVE`, otherwise usage will cause an  invalid-opcode exception (`#UD`).
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "xsave"))]
+	#[inline(always)]
+	pub fn save_32_bit_format_into(&mut self, save_mask: StateComponentBitmap)
+	{
+		self.save_into_internal(save_mask, X87SaveVariant::XSAVE, false, false, |pointer, save_mask| unsafe
+		{
+			_xsave(pointer, save_mask);
+		})
+	}
+
 	/// Saves a `XSAVE` area, compacted, into newly allocated memory.
 	///
 	/// Will only work if the Operating System (strictly speaking, code in kernel mode) has set bit 18 in the register `CR4.OSXSAVE`, otherwise usage will cause an  invalid-opcode exception (`#UD`).
@@ -65,7 +168,21 @@ impl<Allocator: Alloc> XSaveArea<Allocator>
 	#[inline(always)]
 	pub fn save_compacted(allocator: Allocator, extended_state_information: &ExtendedStateInformation, save_mask: StateComponentBitmap) -> Result<Self, AllocErr>
 	{
-		Self::save_internal(allocator, extended_state_information, save_mask, |pointer, save_mask| unsafe
+		let mut this = Self::new_empty(allocator, extended_state_information.xsave_area_size_supported_features)?;
+		this.save_compacted_into(save_mask);
+		Ok(this)
+	}
+
+	/// Saves into this `XSAVE` area, compacted, reusing its memory; see `save_compacted()`.
+	///
+	/// Panics if the capacity of this area is insufficient for the state components in `save_mask` that are enabled in `XCR0`.
+	///
+	/// Will only work if the Operating System (strictly speaking, code in kernel mode) has set bit 18 in the register `CR4.OSXSAVE`, otherwise usage will cause an  invalid-opcode exception (`#UD`).
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "xsave", target_feature = "xsavec"))]
+	#[inline(always)]
+	pub fn save_compacted_into(&mut self, save_mask: StateComponentBitmap)
+	{
+		self.save_into_internal(save_mask, Self::NativeX87SaveVariant, true, false, |pointer, save_mask| unsafe
 		{
 			#[cfg(target_arch = "x86")] _xsavec(pointer, save_mask);
 			#[cfg(target_arch = "x86_64")] _xsavec64(pointer, save_mask);
@@ -81,15 +198,92 @@ impl<Allocator: Alloc> XSaveArea<Allocator>
 	#[inline(always)]
 	pub fn save_using_xcr0_options(allocator: Allocator, extended_state_information: &ExtendedStateInformation, save_mask: StateComponentBitmap) -> Result<Self, AllocErr>
 	{
-		Self::save_internal(allocator, extended_state_information, save_mask, |pointer, save_mask| unsafe
+		let mut this = Self::new_empty(allocator, extended_state_information.xsave_area_size_supported_features)?;
+		this.save_using_xcr0_options_into(save_mask);
+		Ok(this)
+	}
+
+	/// Saves into this `XSAVE` area, using options in `XCR0`, reusing its memory; see `save_using_xcr0_options()`.
+	///
+	/// The modified optimization of `XSAVEOPT`, which skips state components not modified since they were last restored, is only used when the area was last restored from the same address; reusing the area makes this possible.
+	///
+	/// The `XSTATE_BV` bits of state components not in `save_mask` are left unchanged, so the same `save_mask` should be used each time.
+	///
+	/// Panics if the capacity of this area is insufficient for the state components in `save_mask` that are enabled in `XCR0`.
+	///
+	/// Will only work if the Operating System (strictly speaking, code in kernel mode) has set bit 18 in the register `CR4.OSXSAVE`, otherwise usage will cause an  invalid-opcode exception (`#UD`).
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "xsave", target_feature = "xsaveopt"))]
+	#[inline(always)]
+	pub fn save_using_xcr0_options_into(&mut self, save_mask: StateComponentBitmap)
+	{
+		self.save_into_internal(save_mask, Self::NativeX87SaveVariant, false, false, |pointer, save_mask| unsafe
 		{
 			#[cfg(target_arch = "x86")] _xsaveopt(pointer, save_mask);
 			#[cfg(target_arch = "x86_64")] _xsaveopt64(pointer, save_mask);
 		})
 	}
 
+	/// Saves a `XSAVE` area, compacted and including supervisor state components, into newly allocated memory.
+	///
+	/// State components are saved if set in `save_mask` and enabled in either `XCR0` or the `IA32_XSS` `MSR` (see `StateComponentBitmap::save_from_ia32_xss()`).
+	/// The size of the area is taken from `extended_state_information.xsaves_area_size_enabled_features`, so `extended_state_information` must have been created after `IA32_XSS` was last changed.
+	///
+	/// Only allowed for kernel-mode code; use in other modes will cause a general-protection fault (`#GP`).
+	///
+	/// Will only work if the Operating System (strictly speaking, code in kernel mode) has set bit 18 in the register `CR4.OSXSAVE`, otherwise usage will cause an  invalid-opcode exception (`#UD`).
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "xsave", target_feature = "xsaves"))]
+	#[inline(always)]
+	pub fn save_supervisor(allocator: Allocator, extended_state_information: &ExtendedStateInformation, save_mask: StateComponentBitmap) -> Result<Self, AllocErr>
+	{
+		let mut this = Self::new_empty(allocator, extended_state_information.xsaves_area_size_enabled_features)?;
+		this.save_supervisor_into(save_mask);
+		Ok(this)
+	}
+
+	/// Saves into this `XSAVE` area, compacted and including supervisor state components, reusing its memory; see `save_supervisor()`.
+	///
+	/// Only allowed for kernel-mode code; use in other modes will cause a general-protection fault (`#GP`).
+	///
+	/// Panics if the capacity of this area is insufficient for the state components in `save_mask` that are enabled in `XCR0`, and for all the supervisor state components in `save_mask`; `save_mask` should therefore only contain those supervisor state components enabled in `IA32_XSS`.
+	///
+	/// Will only work if the Operating System (strictly speaking, code in kernel mode) has set bit 18 in the register `CR4.OSXSAVE`, otherwise usage will cause an  invalid-opcode exception (`#UD`).
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "xsave", target_feature = "xsaves"))]
+	#[inline(always)]
+	pub fn save_supervisor_into(&mut self, save_mask: StateComponentBitmap)
+	{
+		self.save_into_internal(save_mask, Self::NativeX87SaveVariant, true, true, |pointer, save_mask| unsafe
+		{
+			#[cfg(target_arch = "x86")] _xsaves(pointer, save_mask);
+			#[cfg(target_arch = "x86_64")] _xsaves64(pointer, save_mask);
+		})
+	}
+
+	/// Restores this `XSAVE` area, which must be compacted, including supervisor state components.
+	///
+	/// State components are restored if set in `mask` and enabled in either `XCR0` or the `IA32_XSS` `MSR`.
+	///
+	/// Only allowed for kernel-mode code; use in other modes will cause a general-protection fault (`#GP`).
+	/// A general-protection fault (`#GP`) is also caused if the area is in the standard (uncompacted) format, or if `XSTATE_BV` has bits set that are not set in `XCOMP_BV`.
+	///
+	/// Will only work if the Operating System (strictly speaking, code in kernel mode) has set bit 18 in the register `CR4.OSXSAVE`, otherwise usage will cause an  invalid-opcode exception (`#UD`).
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "xsave", target_feature = "xsaves"))]
+	#[inline(always)]
+	pub fn restore_supervisor(&self, mask: StateComponentBitmap)
+	{
+		let pointer = self.pointer.as_ptr() as *const XSaveAreaLayout as *const u8;
+
+		let mask = mask.0;
+		unsafe
+		{
+			#[cfg(target_arch = "x86")] _xrstors(pointer, mask);
+			#[cfg(target_arch = "x86_64")] _xrstors64(pointer, mask);
+		}
+	}
+
 	/// Restores this `XSAVE` area.
 	///
+	/// Uses the form of `XRSTOR` that matches `save_variant()`, so that an area saved with `save_32_bit_format()` is restored with the 32-bit format of `XRSTOR`.
+	///
 	/// Will only work if the Operating System (strictly speaking, code in kernel mode) has set bit 18 in the register `CR4.OSXSAVE`, otherwise usage will cause an  invalid-opcode exception (`#UD`).
 	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "xsave"))]
 	#[inline(always)]
@@ -101,34 +295,125 @@ impl<Allocator: Alloc> XSaveArea<Allocator>
 		unsafe
 		{
 			#[cfg(target_arch = "x86")] _xrstor(pointer, mask);
-			#[cfg(target_arch = "x86_64")] _xrstor64(pointer, mask);
+			#[cfg(target_arch = "x86_64")]
+			{
+				if self.save_variant.saves_64_bit_offsets()
+				{
+					_xrstor64(pointer, mask)
+				}
+				else
+				{
+					_xrstor(pointer, mask)
+				}
+			}
+		}
+	}
+
+	/// Restores this `XSAVE` area using the 32-bit format of `XRSTOR` (ie without `REX.W`), even on x86_64.
+	///
+	/// `restore()` already does this for areas saved with `save_32_bit_format()`; use this to force the 32-bit format regardless of `save_variant()`.
+	///
+	/// Will only work if the Operating System (strictly speaking, code in kernel mode) has set bit 18 in the register `CR4.OSXSAVE`, otherwise usage will cause an  invalid-opcode exception (`#UD`).
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "xsave"))]
+	#[inline(always)]
+	pub fn restore_32_bit_format(&self, mask: StateComponentBitmap)
+	{
+		let pointer = self.pointer.as_ptr() as *const XSaveAreaLayout as *const u8;
+
+		unsafe { _xrstor(pointer, mask.0) }
+	}
+
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "xsave"))]
+	#[inline(always)]
+	fn save_into_internal(&mut self, save_mask: StateComponentBitmap, save_variant: X87SaveVariant, is_compacted: bool, includes_supervisor_state_components: bool, intrinsic_callback: impl Fn(*mut u8, u64))
+	{
+		// Computing the required capacity uses `XGETBV` and `CPUID`, which are too slow to repeat for every save (eg on every context switch).
+		let key = (save_mask, is_compacted, includes_supervisor_state_components);
+		let required_capacity = match self.required_capacity_cache
+		{
+			Some((cached_key, required_capacity)) if cached_key == key => required_capacity,
+
+			_ =>
+			{
+				let required_capacity = Self::required_capacity(save_mask, is_compacted, includes_supervisor_state_components);
+				self.required_capacity_cache = Some((key, required_capacity));
+				required_capacity
+			}
+		};
+		assert!(self.capacity >= required_capacity, "capacity `{}` is less than the `{}` bytes required to save `{:?}`", self.capacity, required_capacity, save_mask);
+
+		// Only the compacted forms write `XCOMP_BV`; a previous compacted save would otherwise make a standard save look compacted.
+		if !is_compacted
+		{
+			self.xsave_header.XCOMP_BV = CompactionStateComponentBitmap::default()
 		}
+
+		intrinsic_callback(self.pointer.as_ptr() as *mut u8, save_mask.0);
+		self.save_variant = save_variant
 	}
 
+	/// Size in bytes needed to save the state components in `save_mask` that are enabled in `XCR0` and, if `includes_supervisor_state_components`, all the supervisor state components in `save_mask`.
+	///
+	/// Only computed when `save_mask` (or the form of save) differs from that of the previous save into this area, so an area should not be reused after `XCR0` is changed to enable more state components.
+	///
+	/// See Section 13.4.3 (Extended Region of an XSAVE Area) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture).
 	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "xsave"))]
 	#[inline(always)]
-	fn save_internal(mut allocator: Allocator, extended_state_information: &ExtendedStateInformation, save_mask: StateComponentBitmap, intrinsic_callback: impl Fn(*mut u8, u64)) -> Result<Self, AllocErr>
+	fn required_capacity(save_mask: StateComponentBitmap, is_compacted: bool, includes_supervisor_state_components: bool) -> usize
 	{
-		let xsave_area_size_supported_features = extended_state_information.xsave_area_size_supported_features;
+		#[inline(always)]
+		const fn round_up_64(value: usize) -> usize
+		{
+			(value + (64 - 1)) & !(64 -1)
+		}
 
-		let layout = Self::layout(xsave_area_size_supported_features);
-		let pointer = unsafe { NonNull::new_unchecked(allocator.alloc(layout)?.as_ptr() as *mut XSaveAreaLayout) };
+		let xcr0 = StateComponentBitmap::save_from_xcr0();
+
+		let mut required_capacity = Self::MinimumCapacity;
+		let mut compacted_offset = Self::MinimumCapacity;
+		for bit in 2 .. 63
+		{
+			if !save_mask.bit_set(bit)
+			{
+				continue
+			}
 
-		intrinsic_callback(pointer.as_ptr() as *mut u8, save_mask.0);
+			let sizing = match StateComponentSizing::sizing(bit)
+			{
+				None => continue,
+				Some(sizing) => sizing,
+			};
 
-		Ok
-		(
-			Self
+			let is_saved = match sizing.supported_in
 			{
-				allocator,
-				pointer,
-				xsave_area_size_supported_features,
+				SupportedIn::XCR0 => xcr0.bit_set(bit),
+				SupportedIn::IA32_XSS => includes_supervisor_state_components,
+			};
+			if !is_saved
+			{
+				continue
 			}
-		)
+
+			let ends_at = if is_compacted
+			{
+				if sizing.requires_alignment_if_compacted
+				{
+					compacted_offset = round_up_64(compacted_offset)
+				}
+				compacted_offset += sizing.size;
+				compacted_offset
+			}
+			else
+			{
+				sizing.uncompacted_byte_offset + sizing.size
+			};
+			required_capacity = max(required_capacity, ends_at)
+		}
+		required_capacity
 	}
 
-	fn layout(xsave_area_size_enabled_features: usize) -> Layout
+	fn layout(capacity: usize) -> Layout
 	{
-		unsafe { Layout::from_size_align_unchecked(xsave_area_size_enabled_features, 64) }
+		unsafe { Layout::from_size_align_unchecked(capacity, 64) }
 	}
 }
@@ -29,6 +29,13 @@ pub struct XSaveAreaLayout
 
 impl XSaveAreaLayout
 {
+	/// A view of this area as state captured from a 32-bit process; `save_variant` is the variant of instruction that saved this area (see `XSaveArea::save_variant()`).
+	#[inline(always)]
+	pub fn compatibility_mode_state(&self, save_variant: X87SaveVariant) -> CompatibilityModeState<'_>
+	{
+		CompatibilityModeState::from_xsave_area_layout(self, save_variant)
+	}
+
 	/// `x87` state if enabled.
 	///
 	/// The `x87` state is in two non-contiguous areas ('parts').
@@ -61,6 +68,16 @@ impl XSaveAreaLayout
 		}
 	}
 
+	/// `x87` state, first making it present in its initial state (see `materialize()`) if it is not already present.
+	///
+	/// The `x87` state is in two non-contiguous areas ('parts').
+	#[inline(always)]
+	pub fn x87_state_mut_materializing(&mut self) -> (&mut X87StatePart1, &mut X87StatePart2)
+	{
+		self.materialize_initial_state(StateComponent::x87);
+		self.legacy_region.x87_state_mut()
+	}
+
 	/// `SSE` state if enabled.
 	///
 	/// The `SSE` state is in two non-contiguous areas ('parts').
@@ -93,6 +110,16 @@ impl XSaveAreaLayout
 		}
 	}
 
+	/// `SSE` state, first making it present in its initial state (see `materialize()`) if it is not already present.
+	///
+	/// The `SSE` state is in two non-contiguous areas ('parts').
+	#[inline(always)]
+	pub fn sse_state_mut_materializing(&mut self) -> (&mut SseStatePart1, &mut SseStatePart2)
+	{
+		self.materialize_initial_state(StateComponent::SSE);
+		self.legacy_region.sse_state_mut()
+	}
+
 	/// `AVX` user state if enabled.
 	#[inline(always)]
 	pub fn avx_state(&self) -> Option<&[u8]>
@@ -100,25 +127,215 @@ impl XSaveAreaLayout
 		self.extended_state(StateComponent::AVX)
 	}
 
+	/// `AVX` user state if enabled.
+	#[inline(always)]
+	pub fn avx_state_mut(&mut self) -> Option<&mut [u8]>
+	{
+		self.extended_state_mut(StateComponent::AVX)
+	}
+
+	/// Full 256-bit value of the `AVX` register `YMMn`, put together from the `SSE` state (`XMM0`-`XMM15`), the `AVX` state (upper 128 bits of `YMM0`-`YMM15`) and the `AVX-512` `Hi16_ZMM` state (`YMM16`-`YMM31`).
+	///
+	/// State components that are not present are read as their initial value, zero.
+	///
+	/// `n` must be less than 32.
+	#[inline(always)]
+	pub fn ymm(&self, n: u8) -> AvxYmmRegisterValue
+	{
+		assert!(n < 32, "n `{}` is not less than 32", n);
+
+		let mut value = AvxYmmRegisterValue::default();
+		let register_value = &mut value.register_value;
+		if n < 16
+		{
+			self.read_sse_xmm(n, array_mut_ref!(register_value, 0, 16));
+			self.read_extended_state(StateComponent::AVX, (n as usize) * 16, &mut register_value[16 .. 32]);
+		}
+		else
+		{
+			self.read_extended_state(StateComponent::Hi16_ZMM_AVX_512, ((n - 16) as usize) * 64, &mut register_value[..]);
+		}
+		value
+	}
+
+	/// Sets the full 256-bit value of the `AVX` register `YMMn`; see `ymm()`.
+	///
+	/// State components that are not present are first made present in their initial state (and so set in `XSTATE_BV`).
+	/// Returns `false`, changing nothing, if this is not possible (see `can_materialize()`); `capacity` is the size in bytes of the memory holding this layout.
+	///
+	/// `n` must be less than 32.
+	#[inline(always)]
+	pub fn set_ymm(&mut self, n: u8, value: &AvxYmmRegisterValue, capacity: usize) -> bool
+	{
+		assert!(n < 32, "n `{}` is not less than 32", n);
+
+		let register_value = &value.register_value;
+		if n < 16
+		{
+			if !self.can_materialize(StateComponent::AVX, capacity)
+			{
+				return false
+			}
+			self.write_extended_state(StateComponent::AVX, (n as usize) * 16, &register_value[16 .. 32]);
+			self.write_sse_xmm(n, array_ref!(register_value, 0, 16));
+		}
+		else
+		{
+			if !self.can_materialize(StateComponent::Hi16_ZMM_AVX_512, capacity)
+			{
+				return false
+			}
+			self.write_extended_state(StateComponent::Hi16_ZMM_AVX_512, ((n - 16) as usize) * 64, &register_value[..]);
+		}
+		true
+	}
+
+	/// Full 512-bit value of the `AVX-512` register `ZMMn`, put together from the `SSE` state (`XMM0`-`XMM15`), the `AVX` state (upper 128 bits of `YMM0`-`YMM15`), the `AVX-512` `ZMM_Hi256` state (upper 256 bits of `ZMM0`-`ZMM15`) and the `AVX-512` `Hi16_ZMM` state (`ZMM16`-`ZMM31`).
+	///
+	/// State components that are not present are read as their initial value, zero.
+	///
+	/// `n` must be less than 32.
+	#[inline(always)]
+	pub fn zmm(&self, n: u8) -> Avx512ZmmRegisterValue
+	{
+		assert!(n < 32, "n `{}` is not less than 32", n);
+
+		let mut value = Avx512ZmmRegisterValue::default();
+		let register_value = &mut value.register_value;
+		if n < 16
+		{
+			self.read_sse_xmm(n, array_mut_ref!(register_value, 0, 16));
+			self.read_extended_state(StateComponent::AVX, (n as usize) * 16, &mut register_value[16 .. 32]);
+			self.read_extended_state(StateComponent::ZMM_Hi256_AVX_512, (n as usize) * 32, &mut register_value[32 .. 64]);
+		}
+		else
+		{
+			self.read_extended_state(StateComponent::Hi16_ZMM_AVX_512, ((n - 16) as usize) * 64, &mut register_value[..]);
+		}
+		value
+	}
+
+	/// Sets the full 512-bit value of the `AVX-512` register `ZMMn`; see `zmm()`.
+	///
+	/// State components that are not present are first made present in their initial state (and so set in `XSTATE_BV`).
+	/// Returns `false`, changing nothing, if this is not possible (see `can_materialize()`); `capacity` is the size in bytes of the memory holding this layout.
+	///
+	/// `n` must be less than 32.
+	#[inline(always)]
+	pub fn set_zmm(&mut self, n: u8, value: &Avx512ZmmRegisterValue, capacity: usize) -> bool
+	{
+		assert!(n < 32, "n `{}` is not less than 32", n);
+
+		let register_value = &value.register_value;
+		if n < 16
+		{
+			if !self.can_materialize(StateComponent::AVX, capacity) || !self.can_materialize(StateComponent::ZMM_Hi256_AVX_512, capacity)
+			{
+				return false
+			}
+			self.write_extended_state(StateComponent::ZMM_Hi256_AVX_512, (n as usize) * 32, &register_value[32 .. 64]);
+			self.write_extended_state(StateComponent::AVX, (n as usize) * 16, &register_value[16 .. 32]);
+			self.write_sse_xmm(n, array_ref!(register_value, 0, 16));
+		}
+		else
+		{
+			if !self.can_materialize(StateComponent::Hi16_ZMM_AVX_512, capacity)
+			{
+				return false
+			}
+			self.write_extended_state(StateComponent::Hi16_ZMM_AVX_512, ((n - 16) as usize) * 64, &register_value[..]);
+		}
+		true
+	}
+
+	/// `MPX` `BNDREGS` user state if enabled.
+	#[inline(always)]
+	pub fn mpx_bdnregs_state(&self) -> Option<&BoundsRegisterState>
+	{
+		self.extended_state_as(StateComponent::BNDREGS_MPX)
+	}
+
 	/// `MPX` `BNDREGS` user state if enabled.
 	#[inline(always)]
-	pub fn mpx_bdnregs_state(&self) -> Option<&[u8]>
+	pub fn mpx_bdnregs_state_mut(&mut self) -> Option<&mut BoundsRegisterState>
+	{
+		self.extended_state_as_mut(StateComponent::BNDREGS_MPX)
+	}
+
+	/// `MPX` `BNDSCR` user state if enabled.
+	#[inline(always)]
+	pub fn mpx_bndcsr_state(&self) -> Option<&BoundsConfigurationState>
 	{
-		self.extended_state(StateComponent::BNDREGS_MPX)
+		self.extended_state_as(StateComponent::BNDCSR_MPX)
 	}
 
 	/// `MPX` `BNDSCR` user state if enabled.
 	#[inline(always)]
-	pub fn mpx_bndcsr_state(&self) -> Option<&[u8]>
+	pub fn mpx_bndcsr_state_mut(&mut self) -> Option<&mut BoundsConfigurationState>
+	{
+		self.extended_state_as_mut(StateComponent::BNDCSR_MPX)
+	}
+
+	/// `AVX-512` `opmask` user state if enabled.
+	#[inline(always)]
+	pub fn avx_512_opmask_state(&self) -> Option<&OpmaskState>
 	{
-		self.extended_state(StateComponent::BNDCSR_MPX)
+		self.extended_state_as(StateComponent::opmask_AVX_512)
 	}
 
 	/// `AVX-512` `opmask` user state if enabled.
 	#[inline(always)]
-	pub fn avx_512_opmask_state(&self) -> Option<&[u8]>
+	pub fn avx_512_opmask_state_mut(&mut self) -> Option<&mut OpmaskState>
 	{
-		self.extended_state(StateComponent::opmask_AVX_512)
+		self.extended_state_as_mut(StateComponent::opmask_AVX_512)
+	}
+
+	/// Sets the `AVX-512` `opmask` user state.
+	///
+	/// If the state component is not present it is first made present (and so set in `XSTATE_BV`), so that a subsequent restore loads these values.
+	/// Returns `false`, changing nothing, if this is not possible (see `can_materialize()`); `capacity` is the size in bytes of the memory holding this layout.
+	#[inline(always)]
+	pub fn set_avx_512_opmask_state(&mut self, opmask_state: &OpmaskState, capacity: usize) -> bool
+	{
+		if !self.can_materialize(StateComponent::opmask_AVX_512, capacity)
+		{
+			return false
+		}
+
+		let source = unsafe { from_raw_parts(opmask_state as *const OpmaskState as *const u8, size_of::<OpmaskState>()) };
+		self.write_extended_state(StateComponent::opmask_AVX_512, 0, source);
+		true
+	}
+
+	/// Value of the `AVX-512` `opmask` register `kn`.
+	///
+	/// If the state component is not present, this is its initial value, zero.
+	///
+	/// `n` must be less than 8.
+	#[inline(always)]
+	pub fn opmask(&self, n: u8) -> u64
+	{
+		self.avx_512_opmask_state().map(|opmask_state| opmask_state.k(n)).unwrap_or(0)
+	}
+
+	/// Sets the value of the `AVX-512` `opmask` register `kn`.
+	///
+	/// If the state component is not present it is first made present in its initial state (and so set in `XSTATE_BV`), so that a subsequent restore loads this value.
+	/// Returns `false`, changing nothing, if this is not possible (see `can_materialize()`); `capacity` is the size in bytes of the memory holding this layout.
+	///
+	/// `n` must be less than 8.
+	#[inline(always)]
+	pub fn set_opmask(&mut self, n: u8, mask: u64, capacity: usize) -> bool
+	{
+		assert!(n < 8, "n `{}` is not less than 8", n);
+
+		if !self.can_materialize(StateComponent::opmask_AVX_512, capacity)
+		{
+			return false
+		}
+
+		self.write_extended_state(StateComponent::opmask_AVX_512, (n as usize) * size_of::<u64>(), &mask.to_le_bytes());
+		true
 	}
 
 	/// `AVX-512` `ZMM_Hi256` user state if enabled.
@@ -128,6 +345,13 @@ impl XSaveAreaLayout
 		self.extended_state(StateComponent::ZMM_Hi256_AVX_512)
 	}
 
+	/// `AVX-512` `ZMM_Hi256` user state if enabled.
+	#[inline(always)]
+	pub fn avx_512_zmm_hi256_state_mut(&mut self) -> Option<&mut [u8]>
+	{
+		self.extended_state_mut(StateComponent::ZMM_Hi256_AVX_512)
+	}
+
 	/// `AVX-512` `Hi16_ZMM` user state if enabled.
 	#[inline(always)]
 	pub fn avx_512_hi16_zmm_state(&self) -> Option<&[u8]>
@@ -135,91 +359,473 @@ impl XSaveAreaLayout
 		self.extended_state(StateComponent::Hi16_ZMM_AVX_512)
 	}
 
+	/// `AVX-512` `Hi16_ZMM` user state if enabled.
+	#[inline(always)]
+	pub fn avx_512_hi16_zmm_state_mut(&mut self) -> Option<&mut [u8]>
+	{
+		self.extended_state_mut(StateComponent::Hi16_ZMM_AVX_512)
+	}
+
+	/// `PKRU` user state if enabled.
+	#[inline(always)]
+	pub fn pkru_state(&self) -> Option<&PkruRegisterValue>
+	{
+		self.extended_state_as(StateComponent::PKRU)
+	}
+
 	/// `PKRU` user state if enabled.
 	#[inline(always)]
-	pub fn pkru_state(&self) -> Option<&[u8]>
+	pub fn pkru_state_mut(&mut self) -> Option<&mut PkruRegisterValue>
+	{
+		self.extended_state_as_mut(StateComponent::PKRU)
+	}
+
+	/// `AMX` `XTILECFG` user state if enabled.
+	#[inline(always)]
+	pub fn amx_tile_config_state(&self) -> Option<&TileConfig>
 	{
-		self.extended_state(StateComponent::PKRU)
+		self.extended_state_as(StateComponent::XTILECFG)
+	}
+
+	/// `AMX` `XTILECFG` user state if enabled.
+	#[inline(always)]
+	pub fn amx_tile_config_state_mut(&mut self) -> Option<&mut TileConfig>
+	{
+		self.extended_state_as_mut(StateComponent::XTILECFG)
+	}
+
+	/// `AMX` `XTILEDATA` user state if enabled.
+	///
+	/// If the `XTILECFG` user state is not enabled then it is treated as being in its initial state, and so no tiles are configured.
+	#[inline(always)]
+	pub fn amx_tile_data_state(&self) -> Option<TileData<'_>>
+	{
+		const Initial: TileConfig = TileConfig::Initial;
+
+		let tile_data = self.extended_state(StateComponent::XTILEDATA)?;
+		let tile_config = self.amx_tile_config_state().unwrap_or(&Initial);
+		Some(TileData::new(tile_data, tile_config))
+	}
+
+	/// `AMX` `XTILEDATA` user state if enabled.
+	///
+	/// The layout of the tiles within these bytes is given by `amx_tile_config_state()`; see `TileData`.
+	#[inline(always)]
+	pub fn amx_tile_data_state_mut(&mut self) -> Option<&mut [u8]>
+	{
+		self.extended_state_mut(StateComponent::XTILEDATA)
+	}
+
+	/// `APX` `EGPR` user state if enabled.
+	#[inline(always)]
+	pub fn apx_extended_general_purpose_registers_state(&self) -> Option<&ExtendedGeneralPurposeRegisters>
+	{
+		self.extended_state_as(StateComponent::EGPR_APX)
+	}
+
+	/// `APX` `EGPR` user state if enabled.
+	#[inline(always)]
+	pub fn apx_extended_general_purpose_registers_state_mut(&mut self) -> Option<&mut ExtendedGeneralPurposeRegisters>
+	{
+		self.extended_state_as_mut(StateComponent::EGPR_APX)
+	}
+
+	/// AMD `LWP` user state if enabled.
+	#[inline(always)]
+	pub fn lwp_state(&self) -> Option<&AmdLightweightProfilingState>
+	{
+		self.extended_state_as(StateComponent::LWP)
+	}
+
+	/// AMD `LWP` user state if enabled.
+	#[inline(always)]
+	pub fn lwp_state_mut(&mut self) -> Option<&mut AmdLightweightProfilingState>
+	{
+		self.extended_state_as_mut(StateComponent::LWP)
+	}
+
+	/// `PT` supervisor state if enabled.
+	///
+	/// `number_of_address_ranges` is as for `ProcessorTraceState::new()`.
+	#[inline(always)]
+	pub fn pt_state(&self, number_of_address_ranges: u8) -> Option<ProcessorTraceState<&[u8]>>
+	{
+		self.extended_state(StateComponent::PT).map(|bytes| ProcessorTraceState::new(bytes, number_of_address_ranges))
 	}
 
 	/// `PT` supervisor state if enabled.
+	///
+	/// `number_of_address_ranges` is as for `ProcessorTraceState::new()`.
+	#[inline(always)]
+	pub fn pt_state_mut(&mut self, number_of_address_ranges: u8) -> Option<ProcessorTraceState<&mut [u8]>>
+	{
+		self.extended_state_mut(StateComponent::PT).map(|bytes| ProcessorTraceState::new(bytes, number_of_address_ranges))
+	}
+
+	/// `PASID` supervisor state if enabled.
+	#[inline(always)]
+	pub fn pasid_state(&self) -> Option<&PasidState>
+	{
+		self.extended_state_as(StateComponent::PASID)
+	}
+
+	/// `PASID` supervisor state if enabled.
+	#[inline(always)]
+	pub fn pasid_state_mut(&mut self) -> Option<&mut PasidState>
+	{
+		self.extended_state_as_mut(StateComponent::PASID)
+	}
+
+	/// `CET_U` supervisor state if enabled.
+	#[inline(always)]
+	pub fn cet_user_state(&self) -> Option<&CetUserState>
+	{
+		self.extended_state_as(StateComponent::CET_U)
+	}
+
+	/// `CET_U` supervisor state if enabled.
+	#[inline(always)]
+	pub fn cet_user_state_mut(&mut self) -> Option<&mut CetUserState>
+	{
+		self.extended_state_as_mut(StateComponent::CET_U)
+	}
+
+	/// `CET_S` supervisor state if enabled.
+	#[inline(always)]
+	pub fn cet_supervisor_state(&self) -> Option<&CetSupervisorState>
+	{
+		self.extended_state_as(StateComponent::CET_S)
+	}
+
+	/// `CET_S` supervisor state if enabled.
 	#[inline(always)]
-	pub fn pt_state(&self) -> Option<&[u8]>
+	pub fn cet_supervisor_state_mut(&mut self) -> Option<&mut CetSupervisorState>
 	{
-		self.extended_state(StateComponent::PT)
+		self.extended_state_as_mut(StateComponent::CET_S)
 	}
 
 	/// `HDC` supervisor state if enabled.
 	#[inline(always)]
-	pub fn hdc_state(&self) -> Option<&[u8]>
+	pub fn hdc_state(&self) -> Option<&HdcState>
 	{
-		self.extended_state(StateComponent::HDC)
+		self.extended_state_as(StateComponent::HDC)
 	}
 
+	/// `HDC` supervisor state if enabled.
 	#[inline(always)]
-	fn extended_state(&self, state_component: StateComponent) -> Option<&[u8]>
+	pub fn hdc_state_mut(&mut self) -> Option<&mut HdcState>
 	{
-		if self.is_missing(state_component)
+		self.extended_state_as_mut(StateComponent::HDC)
+	}
+
+	/// `UINTR` supervisor state if enabled.
+	#[inline(always)]
+	pub fn uintr_state(&self) -> Option<&UserInterruptState>
+	{
+		self.extended_state_as(StateComponent::UINTR)
+	}
+
+	/// `UINTR` supervisor state if enabled.
+	#[inline(always)]
+	pub fn uintr_state_mut(&mut self) -> Option<&mut UserInterruptState>
+	{
+		self.extended_state_as_mut(StateComponent::UINTR)
+	}
+
+	/// `LBR` supervisor state if enabled.
+	#[inline(always)]
+	pub fn lbr_state(&self) -> Option<ArchitecturalLastBranchRecordState<&[u8]>>
+	{
+		self.extended_state(StateComponent::LBR).map(ArchitecturalLastBranchRecordState::new)
+	}
+
+	/// `LBR` supervisor state if enabled.
+	#[inline(always)]
+	pub fn lbr_state_mut(&mut self) -> Option<ArchitecturalLastBranchRecordState<&mut [u8]>>
+	{
+		self.extended_state_mut(StateComponent::LBR).map(ArchitecturalLastBranchRecordState::new)
+	}
+
+	/// `HWP` supervisor state if enabled.
+	#[inline(always)]
+	pub fn hwp_state(&self) -> Option<&HwpState>
+	{
+		self.extended_state_as(StateComponent::HWP)
+	}
+
+	/// `HWP` supervisor state if enabled.
+	#[inline(always)]
+	pub fn hwp_state_mut(&mut self) -> Option<&mut HwpState>
+	{
+		self.extended_state_as_mut(StateComponent::HWP)
+	}
+
+	/// Raw bytes of a state component, known or unknown, if enabled.
+	///
+	/// Always `None` for the `x87` and `SSE` state components, which are in the legacy region; use `x87_state()` and `sse_state()` instead.
+	#[inline(always)]
+	pub fn component(&self, state_component: StateComponent) -> Option<&[u8]>
+	{
+		self.extended_state(state_component)
+	}
+
+	/// Raw bytes of a state component, known or unknown, if enabled.
+	///
+	/// Always `None` for the `x87` and `SSE` state components, which are in the legacy region; use `x87_state_mut()` and `sse_state_mut()` instead.
+	#[inline(always)]
+	pub fn component_mut(&mut self, state_component: StateComponent) -> Option<&mut [u8]>
+	{
+		self.extended_state_mut(state_component)
+	}
+
+	/// Raw bytes of a state component, known or unknown, first making it present in its initial state (see `materialize()`) if it is not already present.
+	///
+	/// Always `None` for the `x87` and `SSE` state components, which are in the legacy region; use `x87_state_mut_materializing()` and `sse_state_mut_materializing()` instead.
+	/// Also `None` if the state component can not be made present (see `can_materialize()`); `capacity` is the size in bytes of the memory holding this layout.
+	#[inline(always)]
+	pub fn component_mut_materializing(&mut self, state_component: StateComponent, capacity: usize) -> Option<&mut [u8]>
+	{
+		if state_component.bit() < 2 || !self.materialize(state_component, capacity)
 		{
 			None
 		}
 		else
 		{
-			let sizing = match state_component.sizing()
+			self.extended_state_mut(state_component)
+		}
+	}
+
+	/// Can a state component be made present, if it is not already?
+	///
+	/// Not possible if the extended region is compacted and the state component is not in `XCOMP_BV`, if the processor does not support the state component, if the state component would not fit within `capacity`, the size in bytes of the memory holding this layout, or, in the standard format, if an overlapping state component is present or the state component is a supervisor state component (these are only ever saved in the compacted format by `XSAVES`).
+	#[inline(always)]
+	pub fn can_materialize(&self, state_component: StateComponent, capacity: usize) -> bool
+	{
+		if self.is_present(state_component)
+		{
+			return true
+		}
+
+		let bit = state_component.bit();
+		if bit < 2
+		{
+			return true
+		}
+
+		let sizing = match state_component.sizing()
+		{
+			None => return false,
+			Some(sizing) => sizing,
+		};
+
+		let can_be_placed = if self.is_extended_region_uncompacted()
+		{
+			sizing.supported_in == SupportedIn::XCR0 && !self.overlapping_state_component_is_present(state_component)
+		}
+		else
+		{
+			self.xsave_header.XCOMP_BV.bit_set(bit)
+		};
+
+		can_be_placed && self.extended_state_offset(state_component, sizing) + sizing.size <= capacity
+	}
+
+	/// Makes a state component present in its initial state, if it is not already present, and sets it in `XSTATE_BV`, so that a subsequent restore loads it from this area rather than initializing it.
+	///
+	/// The initial state is that defined in Section 13.6 (Processor Tracking of XSAVE-Managed State) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture):-
+	///
+	/// * For the `x87` state, an `FCW` of `0x037F`, all x87 FPU data registers empty and everything else zero;
+	/// * For the `SSE` state, `XMM0`-`XMM15` zero (the `MXCSR` is not part of the initial state);
+	/// * For all other state components, all zeros.
+	///
+	/// Returns `false`, changing nothing, if this is not possible (see `can_materialize()`); `capacity` is the size in bytes of the memory holding this layout.
+	#[inline(always)]
+	pub fn materialize(&mut self, state_component: StateComponent, capacity: usize) -> bool
+	{
+		if !self.can_materialize(state_component, capacity)
+		{
+			return false
+		}
+
+		self.materialize_initial_state(state_component);
+		true
+	}
+
+	/// The state component must be able to be materialized.
+	#[inline(always)]
+	fn materialize_initial_state(&mut self, state_component: StateComponent)
+	{
+		if self.is_present(state_component)
+		{
+			return
+		}
+
+		if state_component == StateComponent::x87
+		{
+			self.legacy_region.x87_state_part_1 = X87StatePart1::initial();
+			self.legacy_region.x87_state_part_2 = X87StatePart2::default();
+		}
+		else if state_component == StateComponent::SSE
+		{
+			self.legacy_region.sse_state_part_2 = SseStatePart2::default();
+		}
+		else
+		{
+			let sizing = state_component.sizing().unwrap();
+			let offset = self.extended_state_offset(state_component, sizing);
+			unsafe { ((self as *mut Self as *mut u8).add(offset)).write_bytes(0, sizing.size) }
+		}
+
+		self.xsave_header.XSTATE_BV.set_is_present(state_component);
+	}
+
+	/// `None` if the state component is missing or is smaller than `T`, as an unknown or future processor might report.
+	#[inline(always)]
+	fn extended_state_as<T>(&self, state_component: StateComponent) -> Option<&T>
+	{
+		self.extended_state(state_component).and_then(|extended_state|
+		{
+			if extended_state.len() < size_of::<T>()
+			{
+				None
+			}
+			else
 			{
-				None => return None,
-				Some(sizing) => sizing,
-			};
+				Some(unsafe { & * (extended_state.as_ptr() as *const T) })
+			}
+		})
+	}
 
-			// Based on Section 13.4.3 (Extended Region of an XSAVE Area) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture).
-			let offset = if self.is_extended_region_uncompacted()
+	/// `None` if the state component is missing or is smaller than `T`, as an unknown or future processor might report.
+	#[inline(always)]
+	fn extended_state_as_mut<T>(&mut self, state_component: StateComponent) -> Option<&mut T>
+	{
+		self.extended_state_mut(state_component).and_then(|extended_state|
+		{
+			if extended_state.len() < size_of::<T>()
 			{
-				sizing.uncompacted_byte_offset
+				None
 			}
 			else
 			{
-				const BaseOffset: usize = 576;
+				Some(unsafe { &mut * (extended_state.as_mut_ptr() as *mut T) })
+			}
+		})
+	}
 
-				// "If XCOMP_BV[j] = 0 for every j, 2 ≤ j < i, locationI is 576".
-				let mask = (1 << (state_component.bit() + 1)) & 0b00;
-				let offset = if self.xsave_header.XCOMP_BV.bits_are_zero(mask)
-				{
-					BaseOffset
-				}
-				else
-				{
-					let mut offset = BaseOffset;
-					for bit in 2 .. (state_component.bit() - 1)
-					{
-						if self.xsave_header.XCOMP_BV.bit_set(bit)
-						{
-							if let Some(sizing) = StateComponentSizing::sizing(bit)
-							{
-								offset += sizing.size;
-								if sizing.requires_alignment_if_compacted
-								{
-									#[inline(always)]
-									const fn round_up_64(value: usize) -> usize
-									{
-										(value + (64 - 1)) & !(64 -1)
-									}
-
-									offset = round_up_64(offset)
-								}
-							}
-						}
-					}
-					offset
-				};
+	#[inline(always)]
+	fn read_sse_xmm(&self, n: u8, destination: &mut [u8; 16])
+	{
+		if self.is_present(StateComponent::SSE)
+		{
+			destination.copy_from_slice(&self.legacy_region.sse_state_part_2.xmm(n).register_value)
+		}
+	}
+
+	#[inline(always)]
+	fn write_sse_xmm(&mut self, n: u8, source: &[u8; 16])
+	{
+		self.materialize_initial_state(StateComponent::SSE);
+		self.legacy_region.sse_state_part_2.xmm_mut(n).register_value.copy_from_slice(source)
+	}
 
-				offset
-			};
+	/// Leaves `destination` unchanged if the state component is not present.
+	#[inline(always)]
+	fn read_extended_state(&self, state_component: StateComponent, offset: usize, destination: &mut [u8])
+	{
+		if let Some(extended_state) = self.extended_state(state_component)
+		{
+			destination.copy_from_slice(&extended_state[offset .. offset + destination.len()])
+		}
+	}
+
+	/// The state component must be able to be materialized.
+	#[inline(always)]
+	fn write_extended_state(&mut self, state_component: StateComponent, offset: usize, source: &[u8])
+	{
+		self.materialize_initial_state(state_component);
+
+		let extended_state = self.extended_state_mut(state_component).unwrap();
+		extended_state[offset .. offset + source.len()].copy_from_slice(source)
+	}
+
+	#[inline(always)]
+	fn extended_state_mut(&mut self, state_component: StateComponent) -> Option<&mut [u8]>
+	{
+		if self.is_missing(state_component)
+		{
+			None
+		}
+		else
+		{
+			let sizing = state_component.sizing()?;
+			let offset = self.extended_state_offset(state_component, sizing);
+
+			let starts_at_pointer = (self as *mut Self as usize) + offset;
+			Some(unsafe { from_raw_parts_mut(starts_at_pointer as *mut u8, sizing.size) })
+		}
+	}
+
+	#[inline(always)]
+	fn extended_state(&self, state_component: StateComponent) -> Option<&[u8]>
+	{
+		if self.is_missing(state_component)
+		{
+			None
+		}
+		else
+		{
+			let sizing = state_component.sizing()?;
+			let offset = self.extended_state_offset(state_component, sizing);
 
 			let starts_at_pointer = (self as *const Self as usize) + offset;
 			Some(unsafe { from_raw_parts(starts_at_pointer as *const u8, sizing.size) })
 		}
 	}
 
+	/// Based on Section 13.4.3 (Extended Region of an XSAVE Area) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture).
+	#[inline(always)]
+	fn extended_state_offset(&self, state_component: StateComponent, sizing: StateComponentSizing) -> usize
+	{
+		if self.is_extended_region_uncompacted()
+		{
+			return sizing.uncompacted_byte_offset
+		}
+
+		#[inline(always)]
+		const fn round_up_64(value: usize) -> usize
+		{
+			(value + (64 - 1)) & !(64 -1)
+		}
+
+		// `XCOMP_BV` includes any supervisor state components saved by `XSAVES`; these are sized by `CPUID` in the same way as user state components.
+		// "If XCOMP_BV[j] = 0 for every j, 2 ≤ j < i, locationI is 576".
+		// Otherwise, "locationI is locationJ + sizeJ" for the greatest such `j` with `XCOMP_BV[j] = 1`, in both cases rounded up to a multiple of 64 if alignment is required for `i`.
+		const BaseOffset: usize = 576;
+
+		let mut offset = BaseOffset;
+		for bit in 2 .. state_component.bit()
+		{
+			if self.xsave_header.XCOMP_BV.bit_set(bit)
+			{
+				if let Some(preceding_sizing) = StateComponentSizing::sizing(bit)
+				{
+					if preceding_sizing.requires_alignment_if_compacted
+					{
+						offset = round_up_64(offset)
+					}
+					offset += preceding_sizing.size;
+				}
+			}
+		}
+
+		if sizing.requires_alignment_if_compacted
+		{
+			offset = round_up_64(offset)
+		}
+		offset
+	}
+
 	#[inline(always)]
 	fn is_extended_region_uncompacted(&self) -> bool
 	{
@@ -232,9 +838,48 @@ impl XSaveAreaLayout
 		!self.is_present(state_component)
 	}
 
+	/// In the standard format, the `MPX` state components are treated as missing if the `APX` state component, which occupies the same bytes, is present.
 	#[inline(always)]
 	fn is_present(&self, state_component: StateComponent) -> bool
 	{
-		self.xsave_header.is_present(state_component)
+		if self.xsave_header.is_present(state_component)
+		{
+			!(Self::is_mpx(state_component) && self.overlapping_state_component_is_present(state_component))
+		}
+		else
+		{
+			false
+		}
+	}
+
+	/// In the standard format, the `APX` state component reuses the offset (and bytes) of the `MPX` state components; the processor never supports both.
+	///
+	/// The offsets reported by `CPUID` for the standard format reflect this, but the state components must never be considered present at the same time.
+	#[inline(always)]
+	fn overlapping_state_component_is_present(&self, state_component: StateComponent) -> bool
+	{
+		if !self.is_extended_region_uncompacted()
+		{
+			return false
+		}
+
+		if Self::is_mpx(state_component)
+		{
+			self.xsave_header.is_present(StateComponent::EGPR_APX)
+		}
+		else if state_component == StateComponent::EGPR_APX
+		{
+			self.xsave_header.is_present(StateComponent::BNDREGS_MPX) || self.xsave_header.is_present(StateComponent::BNDCSR_MPX)
+		}
+		else
+		{
+			false
+		}
+	}
+
+	#[inline(always)]
+	fn is_mpx(state_component: StateComponent) -> bool
+	{
+		state_component == StateComponent::BNDREGS_MPX || state_component == StateComponent::BNDCSR_MPX
 	}
 }
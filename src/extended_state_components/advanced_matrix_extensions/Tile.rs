@@ -0,0 +1,116 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// A view of a configured tile register, `TMMi`, in row-major order.
+///
+/// Rows and columns beyond those configured are not accessible.
+#[derive(Debug, Copy, Clone)]
+pub struct Tile<'a>
+{
+	bytes: &'a [u8],
+
+	rows: u8,
+
+	bytes_per_row: u16,
+}
+
+impl<'a> Tile<'a>
+{
+	/// Number of configured rows.
+	#[inline(always)]
+	pub fn rows(&self) -> usize
+	{
+		self.rows as usize
+	}
+
+	/// Number of configured bytes in each row (`colsb`).
+	#[inline(always)]
+	pub fn bytes_per_row(&self) -> usize
+	{
+		self.bytes_per_row as usize
+	}
+
+	/// Raw bytes of a row (little endian elements).
+	///
+	/// `row` must be less than `self.rows()`.
+	#[inline(always)]
+	pub fn row(&self, row: usize) -> &'a [u8]
+	{
+		assert!(row < self.rows(), "row `{}` is not less than rows `{}`", row, self.rows());
+
+		let starts_at = row * TileConfig::MaximumBytesPerRow;
+		&self.bytes[starts_at .. starts_at + self.bytes_per_row()]
+	}
+
+	/// Number of `int8` columns in each row.
+	#[inline(always)]
+	pub fn int8_columns(&self) -> usize
+	{
+		self.bytes_per_row()
+	}
+
+	/// Number of `bf16` or `fp16` columns in each row.
+	#[inline(always)]
+	pub fn sixteen_bit_columns(&self) -> usize
+	{
+		self.bytes_per_row() / 2
+	}
+
+	/// Element at `(row, column)` interpreted as a signed 8-bit integer (`int8`).
+	#[inline(always)]
+	pub fn int8(&self, row: usize, column: usize) -> i8
+	{
+		self.row(row)[column] as i8
+	}
+
+	/// Element at `(row, column)` interpreted as an unsigned 8-bit integer (`uint8`).
+	#[inline(always)]
+	pub fn uint8(&self, row: usize, column: usize) -> u8
+	{
+		self.row(row)[column]
+	}
+
+	/// Element at `(row, column)` interpreted as a 16-bit brain floating point value (`bf16`).
+	#[inline(always)]
+	pub fn bf16(&self, row: usize, column: usize) -> BFloat16
+	{
+		BFloat16::from_bits(self.sixteen_bits(row, column))
+	}
+
+	/// Element at `(row, column)` interpreted as a 16-bit IEEE 754 half precision floating point value (`fp16`).
+	#[inline(always)]
+	pub fn fp16(&self, row: usize, column: usize) -> Float16
+	{
+		Float16::from_bits(self.sixteen_bits(row, column))
+	}
+
+	/// Row interpreted as signed 8-bit integers (`int8`).
+	#[inline(always)]
+	pub fn int8_row(&self, row: usize) -> impl Iterator<Item=i8> + 'a
+	{
+		self.row(row).iter().map(|byte| *byte as i8)
+	}
+
+	/// Row interpreted as 16-bit brain floating point values (`bf16`).
+	#[inline(always)]
+	pub fn bf16_row(&self, row: usize) -> impl Iterator<Item=BFloat16> + 'a
+	{
+		self.row(row).chunks_exact(2).map(|bytes| BFloat16::from_bits(u16::from_le_bytes([bytes[0], bytes[1]])))
+	}
+
+	/// Row interpreted as 16-bit IEEE 754 half precision floating point values (`fp16`).
+	#[inline(always)]
+	pub fn fp16_row(&self, row: usize) -> impl Iterator<Item=Float16> + 'a
+	{
+		self.row(row).chunks_exact(2).map(|bytes| Float16::from_bits(u16::from_le_bytes([bytes[0], bytes[1]])))
+	}
+
+	#[inline(always)]
+	fn sixteen_bits(&self, row: usize, column: usize) -> u16
+	{
+		let row = self.row(row);
+		let starts_at = column * 2;
+		u16::from_le_bytes([row[starts_at], row[starts_at + 1]])
+	}
+}
@@ -0,0 +1,113 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The 64-byte tile configuration, as loaded by `LDTILECFG` and stored by `STTILECFG` (`XTILECFG` state).
+///
+/// See the description of `LDTILECFG` in the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 2.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct TileConfig
+{
+	palette_id: u8,
+
+	start_row: u8,
+
+	reserved: [u8; 14],
+
+	bytes_per_row: [u16; 16],
+
+	rows: [u8; 16],
+}
+
+impl TileConfig
+{
+	/// The number of tile registers, `TMM0` to `TMM7` inclusive, in palette 1.
+	pub const NumberOfTiles: usize = 8;
+
+	/// The maximum number of rows of a tile in palette 1.
+	pub const MaximumRows: usize = 16;
+
+	/// The maximum number of bytes in a row of a tile in palette 1.
+	pub const MaximumBytesPerRow: usize = 64;
+
+	/// The initial configuration (palette 0), in which no tiles are configured.
+	///
+	/// This is the value when the `XTILECFG` state component is in its initial state.
+	pub const Initial: Self = Self
+	{
+		palette_id: 0,
+		start_row: 0,
+		reserved: [0; 14],
+		bytes_per_row: [0; 16],
+		rows: [0; 16],
+	};
+
+	/// Palette identifier.
+	///
+	/// * `0`: The initial state; no tiles are configured.
+	/// * `1`: 8 tiles, each of at most 16 rows of at most 64 bytes.
+	#[inline(always)]
+	pub fn palette(&self) -> u8
+	{
+		self.palette_id
+	}
+
+	/// Is any tile configured (ie is the palette not `0`)?
+	#[inline(always)]
+	pub fn is_configured(&self) -> bool
+	{
+		self.palette_id != 0
+	}
+
+	/// The row at which a restartable tile instruction (such as `TILELOADD`) that was interrupted will resume.
+	///
+	/// Normally `0`.
+	#[inline(always)]
+	pub fn start_row(&self) -> u8
+	{
+		self.start_row
+	}
+
+	/// Number of rows configured for tile `TMMi`.
+	///
+	/// `tile` must be less than `Self::NumberOfTiles`.
+	#[inline(always)]
+	pub fn rows(&self, tile: usize) -> u8
+	{
+		assert!(tile < Self::NumberOfTiles, "tile `{}` is not less than {}", tile, Self::NumberOfTiles);
+
+		self.rows[tile]
+	}
+
+	/// Number of bytes in each row (`colsb`) configured for tile `TMMi`.
+	///
+	/// `tile` must be less than `Self::NumberOfTiles`.
+	#[inline(always)]
+	pub fn bytes_per_row(&self, tile: usize) -> u16
+	{
+		assert!(tile < Self::NumberOfTiles, "tile `{}` is not less than {}", tile, Self::NumberOfTiles);
+
+		u16::from_le(self.bytes_per_row[tile])
+	}
+
+	/// Is tile `TMMi` configured (ie does it have a non-zero number of rows and bytes per row)?
+	///
+	/// `tile` must be less than `Self::NumberOfTiles`.
+	#[inline(always)]
+	pub fn is_tile_configured(&self, tile: usize) -> bool
+	{
+		self.is_configured() && self.rows(tile) != 0 && self.bytes_per_row(tile) != 0
+	}
+
+	/// Are the number of rows and bytes per row configured for tile `TMMi` within the limits of palette 1 (`Self::MaximumRows` and `Self::MaximumBytesPerRow`)?
+	///
+	/// `LDTILECFG` will not load a configuration exceeding these limits, but a configuration read from elsewhere, such as a core dump, may.
+	///
+	/// `tile` must be less than `Self::NumberOfTiles`.
+	#[inline(always)]
+	pub fn is_tile_within_limits(&self, tile: usize) -> bool
+	{
+		(self.rows(tile) as usize) <= Self::MaximumRows && (self.bytes_per_row(tile) as usize) <= Self::MaximumBytesPerRow
+	}
+}
@@ -0,0 +1,71 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// A view of the tile registers `TMM0` to `TMM7` inclusive (`XTILEDATA` state), interpreted using a tile configuration (`XTILECFG` state).
+///
+/// Each tile register occupies 1024 bytes, as 16 rows of 64 bytes; only the rows and bytes per row configured in the tile configuration are meaningful.
+#[derive(Debug, Copy, Clone)]
+pub struct TileData<'a>
+{
+	tile_data: &'a [u8],
+
+	tile_config: &'a TileConfig,
+}
+
+impl<'a> TileData<'a>
+{
+	const BytesPerTile: usize = TileConfig::MaximumRows * TileConfig::MaximumBytesPerRow;
+
+	/// Creates a new instance.
+	///
+	/// `tile_data` must be at least 8192 bytes long.
+	#[inline(always)]
+	pub fn new(tile_data: &'a [u8], tile_config: &'a TileConfig) -> Self
+	{
+		assert!(tile_data.len() >= TileConfig::NumberOfTiles * Self::BytesPerTile, "tile_data is too short at `{}` bytes", tile_data.len());
+
+		Self
+		{
+			tile_data,
+			tile_config,
+		}
+	}
+
+	/// Tile configuration.
+	#[inline(always)]
+	pub fn tile_config(&self) -> &'a TileConfig
+	{
+		self.tile_config
+	}
+
+	/// Tile `TMMi`.
+	///
+	/// Returns `None` if `tile` is not less than `TileConfig::NumberOfTiles`, the tile is not configured, or its configuration exceeds the limits of palette 1 (see `TileConfig::is_tile_within_limits()`).
+	#[inline(always)]
+	pub fn tile(&self, tile: usize) -> Option<Tile<'a>>
+	{
+		if tile >= TileConfig::NumberOfTiles || !self.tile_config.is_tile_configured(tile) || !self.tile_config.is_tile_within_limits(tile)
+		{
+			return None
+		}
+
+		let starts_at = tile * Self::BytesPerTile;
+		Some
+		(
+			Tile
+			{
+				bytes: &self.tile_data[starts_at .. starts_at + Self::BytesPerTile],
+				rows: self.tile_config.rows(tile),
+				bytes_per_row: self.tile_config.bytes_per_row(tile),
+			}
+		)
+	}
+
+	/// Configured tiles, `TMM0` to `TMM7` inclusive.
+	#[inline(always)]
+	pub fn tiles(self) -> impl Iterator<Item=(usize, Tile<'a>)>
+	{
+		(0 .. TileConfig::NumberOfTiles).filter_map(move |tile| self.tile(tile).map(|view| (tile, view)))
+	}
+}
@@ -0,0 +1,100 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The sixteen 64-bit Intel® Advanced Performance Extensions (`APX`) extended general purpose registers `R16`–`R31` (`APX` state).
+///
+/// In the standard (uncompacted) format of a `XSAVE` area this state component reuses the bytes of the `MPX` `BNDREGS` and `BNDCSR` state components, as `APX` and `MPX` are never both supported.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct ExtendedGeneralPurposeRegisters
+{
+	/// Extended general purpose register `R16`.
+	pub r16: u64,
+
+	/// Extended general purpose register `R17`.
+	pub r17: u64,
+
+	/// Extended general purpose register `R18`.
+	pub r18: u64,
+
+	/// Extended general purpose register `R19`.
+	pub r19: u64,
+
+	/// Extended general purpose register `R20`.
+	pub r20: u64,
+
+	/// Extended general purpose register `R21`.
+	pub r21: u64,
+
+	/// Extended general purpose register `R22`.
+	pub r22: u64,
+
+	/// Extended general purpose register `R23`.
+	pub r23: u64,
+
+	/// Extended general purpose register `R24`.
+	pub r24: u64,
+
+	/// Extended general purpose register `R25`.
+	pub r25: u64,
+
+	/// Extended general purpose register `R26`.
+	pub r26: u64,
+
+	/// Extended general purpose register `R27`.
+	pub r27: u64,
+
+	/// Extended general purpose register `R28`.
+	pub r28: u64,
+
+	/// Extended general purpose register `R29`.
+	pub r29: u64,
+
+	/// Extended general purpose register `R30`.
+	pub r30: u64,
+
+	/// Extended general purpose register `R31`.
+	pub r31: u64,
+}
+
+impl ExtendedGeneralPurposeRegisters
+{
+	/// Value of extended general purpose register `Rn`.
+	///
+	/// `n` must be between 16 and 31 inclusive.
+	#[inline(always)]
+	pub fn r(&self, n: u8) -> u64
+	{
+		self.registers()[Self::index(n)]
+	}
+
+	/// Sets the value of extended general purpose register `Rn`.
+	///
+	/// `n` must be between 16 and 31 inclusive.
+	#[inline(always)]
+	pub fn set_r(&mut self, n: u8, value: u64)
+	{
+		self.registers_mut()[Self::index(n)] = value
+	}
+
+	#[inline(always)]
+	fn index(n: u8) -> usize
+	{
+		assert!(n >= 16 && n < 32, "n `{}` is not between 16 and 31 inclusive", n);
+
+		(n - 16) as usize
+	}
+
+	#[inline(always)]
+	fn registers(&self) -> &[u64; 16]
+	{
+		unsafe { & * (self as *const Self as *const [u64; 16]) }
+	}
+
+	#[inline(always)]
+	fn registers_mut(&mut self) -> &mut [u64; 16]
+	{
+		unsafe { &mut * (self as *mut Self as *mut [u64; 16]) }
+	}
+}
@@ -0,0 +1,55 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// AMD Lightweight Profiling state (`LWP` state).
+///
+/// See the AMD Lightweight Profiling Specification (publication 43724).
+/// Only the address of the Lightweight Profiling Control Block (`LWPCB`) has an architecturally fixed location; the remainder is a processor-specific copy of the `LWPCB` and ring buffer state.
+#[derive(Clone)]
+#[repr(C)]
+pub struct AmdLightweightProfilingState
+{
+	/// Value of the `LWP_CBADDR` `MSR`.
+	///
+	/// The linear address of the Lightweight Profiling Control Block (`LWPCB`); zero if Lightweight Profiling is disabled.
+	pub lightweight_profiling_control_block_address: u64,
+
+	/// Processor-specific state.
+	pub processor_specific: [u8; 120],
+}
+
+impl Default for AmdLightweightProfilingState
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			lightweight_profiling_control_block_address: 0,
+			processor_specific: [0; 120],
+		}
+	}
+}
+
+impl Debug for AmdLightweightProfilingState
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_struct("AmdLightweightProfilingState")
+		.field("lightweight_profiling_control_block_address", &self.lightweight_profiling_control_block_address)
+		.field("processor_specific", &&self.processor_specific[..])
+		.finish()
+	}
+}
+
+impl AmdLightweightProfilingState
+{
+	/// Is Lightweight Profiling enabled?
+	#[inline(always)]
+	pub fn is_enabled(&self) -> bool
+	{
+		self.lightweight_profiling_control_block_address != 0
+	}
+}
@@ -0,0 +1,182 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Architectural last branch record MSRs (`LBR` state).
+///
+/// The layout is documented in Section 13.5.12 (Arch LBR State) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture).
+/// The number of last branch records is variable, and given by the value of `IA32_LBR_DEPTH`, so this is a view over bytes rather than a fixed-size structure.
+///
+/// `B` is either `&[u8]` (read only) or `&mut [u8]` (read and write).
+#[derive(Copy, Clone)]
+pub struct ArchitecturalLastBranchRecordState<B: AsRef<[u8]>>
+{
+	bytes: B,
+}
+
+impl<B: AsRef<[u8]>> Debug for ArchitecturalLastBranchRecordState<B>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_struct("ArchitecturalLastBranchRecordState")
+		.field("IA32_LBR_CTL", &self.control())
+		.field("IA32_LBR_DEPTH", &self.depth())
+		.field("last_event_record", &self.last_event_record())
+		.field("records", &self.records().collect::<Vec<_>>())
+		.finish()
+	}
+}
+
+impl<B: AsRef<[u8]>> ArchitecturalLastBranchRecordState<B>
+{
+	const IA32_LBR_CTL: usize = 0;
+
+	const IA32_LBR_DEPTH: usize = 8;
+
+	const IA32_LER_FROM_IP: usize = 16;
+
+	/// Immediately follows `IA32_LER_FROM_IP`, `IA32_LER_TO_IP` and `IA32_LER_INFO`; thus a depth of 32 gives a state component of 808 bytes.
+	const IA32_LBR_0_FROM_IP: usize = 40;
+
+	const LastBranchRecordSize: usize = 24;
+
+	/// Creates a new instance.
+	///
+	/// `bytes` must be at least 40 bytes long.
+	#[inline(always)]
+	pub fn new(bytes: B) -> Self
+	{
+		assert!(bytes.as_ref().len() >= Self::IA32_LBR_0_FROM_IP, "bytes are too short");
+
+		Self
+		{
+			bytes,
+		}
+	}
+
+	/// Value of the `IA32_LBR_CTL` MSR.
+	#[inline(always)]
+	pub fn control(&self) -> LastBranchRecordControlRegisterValue
+	{
+		LastBranchRecordControlRegisterValue::new(self.read(Self::IA32_LBR_CTL))
+	}
+
+	/// Value of the `IA32_LBR_DEPTH` MSR.
+	///
+	/// The number of last branch records in use; always a multiple of 8, and at most 64.
+	#[inline(always)]
+	pub fn depth(&self) -> u64
+	{
+		self.read(Self::IA32_LBR_DEPTH)
+	}
+
+	/// Last event record (from the MSRs `IA32_LER_FROM_IP`, `IA32_LER_TO_IP` and `IA32_LER_INFO`).
+	///
+	/// The last branch taken before the most recent exception or interrupt.
+	#[inline(always)]
+	pub fn last_event_record(&self) -> LastBranchRecord
+	{
+		self.record_at(Self::IA32_LER_FROM_IP)
+	}
+
+	/// Number of last branch records.
+	///
+	/// This is `depth()`, limited to the number of last branch records that fit in the state component.
+	#[inline(always)]
+	pub fn number_of_records(&self) -> usize
+	{
+		let fit = (self.bytes.as_ref().len() - Self::IA32_LBR_0_FROM_IP) / Self::LastBranchRecordSize;
+		let depth = self.depth();
+		if depth > (fit as u64)
+		{
+			fit
+		}
+		else
+		{
+			depth as usize
+		}
+	}
+
+	/// Last branch record `IA32_LBR_x`.
+	///
+	/// `IA32_LBR_0` is the most recent branch.
+	///
+	/// Returns `None` if `index` is not less than `number_of_records()`.
+	#[inline(always)]
+	pub fn record(&self, index: usize) -> Option<LastBranchRecord>
+	{
+		if index >= self.number_of_records()
+		{
+			None
+		}
+		else
+		{
+			Some(self.record_at(Self::record_offset(index)))
+		}
+	}
+
+	/// Iterates over the last branch records, from `IA32_LBR_0` upwards.
+	#[inline(always)]
+	pub fn records<'a>(&'a self) -> impl Iterator<Item=LastBranchRecord> + 'a
+	{
+		(0 .. self.number_of_records()).map(move |index| self.record_at(Self::record_offset(index)))
+	}
+
+	#[inline(always)]
+	fn record_offset(index: usize) -> usize
+	{
+		Self::IA32_LBR_0_FROM_IP + index * Self::LastBranchRecordSize
+	}
+
+	#[inline(always)]
+	fn record_at(&self, offset: usize) -> LastBranchRecord
+	{
+		LastBranchRecord
+		{
+			from_ip: self.read(offset),
+			to_ip: self.read(offset + 8),
+			info: LastBranchRecordInfo::new(self.read(offset + 16)),
+		}
+	}
+
+	#[inline(always)]
+	fn read(&self, offset: usize) -> u64
+	{
+		u64::from_le_bytes(*array_ref!(self.bytes.as_ref(), offset, 8))
+	}
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> ArchitecturalLastBranchRecordState<B>
+{
+	/// Sets the value of the `IA32_LBR_CTL` MSR.
+	#[inline(always)]
+	pub fn set_control(&mut self, control: LastBranchRecordControlRegisterValue)
+	{
+		self.write(Self::IA32_LBR_CTL, control.value())
+	}
+
+	/// Sets last branch record `IA32_LBR_x`.
+	///
+	/// Returns `false` if `index` is not less than `number_of_records()`.
+	#[inline(always)]
+	pub fn set_record(&mut self, index: usize, record: &LastBranchRecord) -> bool
+	{
+		if index >= self.number_of_records()
+		{
+			return false
+		}
+
+		let offset = Self::record_offset(index);
+		self.write(offset, record.from_ip);
+		self.write(offset + 8, record.to_ip);
+		self.write(offset + 16, record.info.value());
+		true
+	}
+
+	#[inline(always)]
+	fn write(&mut self, offset: usize, value: u64)
+	{
+		*array_mut_ref!(self.bytes.as_mut(), offset, 8) = value.to_le_bytes()
+	}
+}
@@ -0,0 +1,18 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// An architectural last branch record, from the MSRs `IA32_LBR_x_FROM_IP`, `IA32_LBR_x_TO_IP` and `IA32_LBR_x_INFO`.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct LastBranchRecord
+{
+	/// Linear address of the branch instruction, `IA32_LBR_x_FROM_IP`.
+	pub from_ip: u64,
+
+	/// Linear address of the branch target, `IA32_LBR_x_TO_IP`.
+	pub to_ip: u64,
+
+	/// Branch information, `IA32_LBR_x_INFO`.
+	pub info: LastBranchRecordInfo,
+}
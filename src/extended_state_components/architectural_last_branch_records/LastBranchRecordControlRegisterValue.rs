@@ -0,0 +1,110 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Value of the architectural last branch record control MSR, `IA32_LBR_CTL`.
+///
+/// See Section 18.1.3 (Architectural Last Branch Records) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 3B.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct LastBranchRecordControlRegisterValue(u64);
+
+impl LastBranchRecordControlRegisterValue
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u64) -> Self
+	{
+		Self(value)
+	}
+
+	/// Raw value.
+	#[inline(always)]
+	pub fn value(self) -> u64
+	{
+		self.0
+	}
+
+	/// Branch recording enabled, `LBREn`.
+	#[inline(always)]
+	pub fn enable(self) -> bool
+	{
+		self.bit_set(0)
+	}
+
+	/// Record branches when `CPL = 0`, `OS`.
+	#[inline(always)]
+	pub fn os(self) -> bool
+	{
+		self.bit_set(1)
+	}
+
+	/// Record branches when `CPL > 0`, `USR`.
+	#[inline(always)]
+	pub fn user(self) -> bool
+	{
+		self.bit_set(2)
+	}
+
+	/// Call-stack mode, `CALL_STACK`.
+	#[inline(always)]
+	pub fn call_stack(self) -> bool
+	{
+		self.bit_set(3)
+	}
+
+	/// Record conditional branches, `COND`.
+	#[inline(always)]
+	pub fn conditional(self) -> bool
+	{
+		self.bit_set(16)
+	}
+
+	/// Record near relative jumps, `NEAR_REL_JMP`.
+	#[inline(always)]
+	pub fn near_relative_jump(self) -> bool
+	{
+		self.bit_set(17)
+	}
+
+	/// Record near indirect jumps, `NEAR_IND_JMP`.
+	#[inline(always)]
+	pub fn near_indirect_jump(self) -> bool
+	{
+		self.bit_set(18)
+	}
+
+	/// Record near relative calls, `NEAR_REL_CALL`.
+	#[inline(always)]
+	pub fn near_relative_call(self) -> bool
+	{
+		self.bit_set(19)
+	}
+
+	/// Record near indirect calls, `NEAR_IND_CALL`.
+	#[inline(always)]
+	pub fn near_indirect_call(self) -> bool
+	{
+		self.bit_set(20)
+	}
+
+	/// Record near returns, `NEAR_RET`.
+	#[inline(always)]
+	pub fn near_return(self) -> bool
+	{
+		self.bit_set(21)
+	}
+
+	/// Record other branches (such as far branches, interrupts and exceptions), `OTHER_BRANCH`.
+	#[inline(always)]
+	pub fn other_branch(self) -> bool
+	{
+		self.bit_set(22)
+	}
+
+	#[inline(always)]
+	fn bit_set(self, bit: u64) -> bool
+	{
+		self.0 & (1 << bit) != 0
+	}
+}
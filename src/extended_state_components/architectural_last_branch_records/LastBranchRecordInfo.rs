@@ -0,0 +1,71 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Value of an architectural last branch record information MSR, `IA32_LBR_x_INFO` or `IA32_LER_INFO`.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct LastBranchRecordInfo(u64);
+
+impl LastBranchRecordInfo
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u64) -> Self
+	{
+		Self(value)
+	}
+
+	/// Raw value.
+	#[inline(always)]
+	pub fn value(self) -> u64
+	{
+		self.0
+	}
+
+	/// Elapsed core clocks since the previous last branch record, `CYC_CNT`.
+	///
+	/// Only meaningful if `cycle_count_valid()` is `true`.
+	#[inline(always)]
+	pub fn cycle_count(self) -> u16
+	{
+		self.0 as u16
+	}
+
+	/// Branch type, `BR_TYPE`.
+	///
+	/// A 4-bit (`u4`) value; see Table 18-4 (Branch Type Encodings) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 3B.
+	#[inline(always)]
+	pub fn branch_type(self) -> u8
+	{
+		((self.0 >> 56) & 0b1111) as u8
+	}
+
+	/// Is `cycle_count()` valid, `CYC_CNT_VALID`?
+	#[inline(always)]
+	pub fn cycle_count_valid(self) -> bool
+	{
+		self.0 & (1 << 60) != 0
+	}
+
+	/// The branch was a transactional abort, `TSX_ABORT`.
+	#[inline(always)]
+	pub fn tsx_abort(self) -> bool
+	{
+		self.0 & (1 << 61) != 0
+	}
+
+	/// The branch occurred within a transaction, `IN_TSX`.
+	#[inline(always)]
+	pub fn in_tsx(self) -> bool
+	{
+		self.0 & (1 << 62) != 0
+	}
+
+	/// The branch was mispredicted, `MISPRED`.
+	#[inline(always)]
+	pub fn mispredicted(self) -> bool
+	{
+		self.0 & (1 << 63) != 0
+	}
+}
@@ -0,0 +1,105 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The eight 64-bit `AVX-512` `opmask` registers `k0`–`k7` (`opmask` state).
+///
+/// When fewer than 64 lanes are used by an instruction, only the low bits of an `opmask` register are meaningful.
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct OpmaskState
+{
+	k: [u64; 8],
+}
+
+impl Debug for OpmaskState
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		struct Binary(u64);
+
+		impl Debug for Binary
+		{
+			#[inline(always)]
+			fn fmt(&self, f: &mut Formatter) -> fmt::Result
+			{
+				write!(f, "{:#066b}", self.0)
+			}
+		}
+
+		let mut debug_struct = f.debug_struct("OpmaskState");
+		const Names: [&str; 8] = ["k0", "k1", "k2", "k3", "k4", "k5", "k6", "k7"];
+		for (name, k) in Names.iter().zip(self.k.iter())
+		{
+			debug_struct.field(name, &Binary(u64::from_le(*k)));
+		}
+		debug_struct.finish()
+	}
+}
+
+impl OpmaskState
+{
+	/// Value of the `opmask` register `kn`.
+	///
+	/// `n` must be less than 8.
+	#[inline(always)]
+	pub fn k(&self, n: u8) -> u64
+	{
+		assert!(n < 8, "n `{}` is not less than 8", n);
+
+		u64::from_le(self.k[n as usize])
+	}
+
+	/// Sets the value of the `opmask` register `kn`.
+	///
+	/// `n` must be less than 8.
+	#[inline(always)]
+	pub fn set_k(&mut self, n: u8, mask: u64)
+	{
+		assert!(n < 8, "n `{}` is not less than 8", n);
+
+		self.k[n as usize] = mask.to_le()
+	}
+
+	/// Is `lane` set in the `opmask` register `kn`?
+	///
+	/// `n` must be less than 8 and `lane` must be less than 64.
+	#[inline(always)]
+	pub fn lane(&self, n: u8, lane: u8) -> bool
+	{
+		assert!(lane < 64, "lane `{}` is not less than 64", lane);
+
+		self.k(n) & (1 << lane) != 0
+	}
+
+	/// Sets or clears `lane` in the `opmask` register `kn`.
+	///
+	/// `n` must be less than 8 and `lane` must be less than 64.
+	#[inline(always)]
+	pub fn set_lane(&mut self, n: u8, lane: u8, set: bool)
+	{
+		assert!(lane < 64, "lane `{}` is not less than 64", lane);
+
+		let mask = self.k(n);
+		let mask = if set
+		{
+			mask | (1 << lane)
+		}
+		else
+		{
+			mask & !(1 << lane)
+		};
+		self.set_k(n, mask)
+	}
+
+	/// Iterates over the 64 lanes of the `opmask` register `kn`, from lane 0 (the least significant bit) upwards.
+	///
+	/// `n` must be less than 8.
+	#[inline(always)]
+	pub fn lanes(&self, n: u8) -> impl Iterator<Item=bool>
+	{
+		let mask = self.k(n);
+		(0 .. 64).map(move |lane| mask & (1 << lane) != 0)
+	}
+}
@@ -0,0 +1,135 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The value of a Control-flow Enforcement Technology control MSR, either `IA32_U_CET` (user mode) or `IA32_S_CET` (supervisor mode).
+///
+/// See Chapter 17 (Control-flow Enforcement Technology) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture).
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct CetControlRegisterValue(u64);
+
+impl CetControlRegisterValue
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u64) -> Self
+	{
+		Self(value)
+	}
+
+	/// Raw value.
+	#[inline(always)]
+	pub fn value(self) -> u64
+	{
+		self.0
+	}
+
+	/// Shadow stack enabled, `SH_STK_EN`.
+	#[inline(always)]
+	pub fn shadow_stack_enable(self) -> bool
+	{
+		self.bit_set(0)
+	}
+
+	/// Writes to the shadow stack using `WRSS` are enabled, `WR_SHSTK_EN`.
+	#[inline(always)]
+	pub fn write_shadow_stack_enable(self) -> bool
+	{
+		self.bit_set(1)
+	}
+
+	/// Indirect branch tracking enabled, `ENDBR_EN`.
+	#[inline(always)]
+	pub fn end_branch_enable(self) -> bool
+	{
+		self.bit_set(2)
+	}
+
+	/// Legacy compatibility treatment enabled for indirect branch tracking, `LEG_IW_EN`.
+	#[inline(always)]
+	pub fn legacy_indirect_branch_treatment_enable(self) -> bool
+	{
+		self.bit_set(3)
+	}
+
+	/// The `3EH` (`NOTRACK`) prefix is honoured on indirect `CALL` and `JMP`, `NO_TRACK_EN`.
+	#[inline(always)]
+	pub fn no_track_enable(self) -> bool
+	{
+		self.bit_set(4)
+	}
+
+	/// Suppression of indirect branch tracking is disabled when a legacy compatibility bitmap lookup succeeds, `SUPPRESS_DIS`.
+	#[inline(always)]
+	pub fn suppress_disable(self) -> bool
+	{
+		self.bit_set(5)
+	}
+
+	/// Indirect branch tracking is suppressed, `SUPPRESS`.
+	#[inline(always)]
+	pub fn suppress(self) -> bool
+	{
+		self.bit_set(10)
+	}
+
+	/// The indirect branch tracker state, `TRACKER`.
+	///
+	/// When `true` the tracker is in the `WAIT_FOR_ENDBRANCH` state, ie an indirect `CALL` or `JMP` has been executed and the next instruction must be an `ENDBR32` or `ENDBR64`.
+	/// If a control protection fault (`#CP`) occurred with this set then the target of an indirect branch was missing an `ENDBR` instruction.
+	#[inline(always)]
+	pub fn tracker_is_waiting_for_end_branch(self) -> bool
+	{
+		self.bit_set(11)
+	}
+
+	/// Linear address of the legacy code page bitmap, `EB_LEG_BITMAP_BASE`, used when `legacy_indirect_branch_treatment_enable()` is `true`.
+	///
+	/// Always 4KB aligned.
+	#[inline(always)]
+	pub fn legacy_code_page_bitmap_base(self) -> u64
+	{
+		self.0 & !0xFFF
+	}
+
+	/// Set shadow stack enabled, `SH_STK_EN`.
+	#[inline(always)]
+	pub fn set_shadow_stack_enable(&mut self, shadow_stack_enable: bool)
+	{
+		self.set_bit(0, shadow_stack_enable)
+	}
+
+	/// Set writes to the shadow stack using `WRSS` are enabled, `WR_SHSTK_EN`.
+	#[inline(always)]
+	pub fn set_write_shadow_stack_enable(&mut self, write_shadow_stack_enable: bool)
+	{
+		self.set_bit(1, write_shadow_stack_enable)
+	}
+
+	/// Set indirect branch tracking enabled, `ENDBR_EN`.
+	#[inline(always)]
+	pub fn set_end_branch_enable(&mut self, end_branch_enable: bool)
+	{
+		self.set_bit(2, end_branch_enable)
+	}
+
+	#[inline(always)]
+	fn bit_set(self, bit: u64) -> bool
+	{
+		self.0 & (1 << bit) != 0
+	}
+
+	#[inline(always)]
+	fn set_bit(&mut self, bit: u64, set: bool)
+	{
+		if set
+		{
+			self.0 |= 1 << bit
+		}
+		else
+		{
+			self.0 &= !(1 << bit)
+		}
+	}
+}
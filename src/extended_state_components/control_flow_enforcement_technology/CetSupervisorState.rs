@@ -0,0 +1,20 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Supervisor-mode Control-flow Enforcement Technology state (`CET_S` state).
+///
+/// Contains the shadow stack pointers for privilege levels 0, 1 and 2; the supervisor-mode control MSR `IA32_S_CET` is not part of any state component.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct CetSupervisorState
+{
+	/// Value of the `IA32_PL0_SSP` MSR.
+	pub privilege_level_0_shadow_stack_pointer: ShadowStackPointer,
+
+	/// Value of the `IA32_PL1_SSP` MSR.
+	pub privilege_level_1_shadow_stack_pointer: ShadowStackPointer,
+
+	/// Value of the `IA32_PL2_SSP` MSR.
+	pub privilege_level_2_shadow_stack_pointer: ShadowStackPointer,
+}
@@ -0,0 +1,65 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// User-mode Control-flow Enforcement Technology state (`CET_U` state).
+///
+/// Saved and restored by `XSAVES` and `XRSTORS` even though it is user-mode state.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct CetUserState
+{
+	/// Value of the `IA32_U_CET` MSR.
+	pub user_control: CetControlRegisterValue,
+
+	/// Value of the `IA32_PL3_SSP` MSR (user-mode shadow stack pointer).
+	pub privilege_level_3_shadow_stack_pointer: ShadowStackPointer,
+}
+
+impl CetUserState
+{
+	/// Linux `NT_X86_SHSTK` core dump note type.
+	pub const NT_X86_SHSTK: u32 = 0x204;
+
+	/// Parses the descriptor of a Linux `NT_X86_SHSTK` core dump (or `PTRACE_GETREGSET`) note.
+	///
+	/// The note contains only the user-mode shadow stack pointer, and is only present if the shadow stack was enabled; `user_control` is therefore populated with only `SH_STK_EN` set.
+	///
+	/// Returns `None` if `note_descriptor` is not exactly 8 bytes long.
+	#[inline(always)]
+	pub fn from_linux_nt_x86_shstk_note(note_descriptor: &[u8]) -> Option<Self>
+	{
+		if note_descriptor.len() != 8
+		{
+			return None
+		}
+
+		let mut user_control = CetControlRegisterValue::default();
+		user_control.set_shadow_stack_enable(true);
+
+		Some
+		(
+			Self
+			{
+				user_control,
+				privilege_level_3_shadow_stack_pointer: ShadowStackPointer::new(u64::from_le_bytes(*array_ref!(note_descriptor, 0, 8))),
+			}
+		)
+	}
+
+	/// Is the user-mode shadow stack enabled?
+	#[inline(always)]
+	pub fn is_shadow_stack_enabled(&self) -> bool
+	{
+		self.user_control.shadow_stack_enable()
+	}
+
+	/// Was a control protection fault (`#CP`) a shadow stack violation (a mismatched return address) rather than an indirect branch tracking violation (a missing `ENDBR` instruction)?
+	///
+	/// Only meaningful for state saved when handling a `#CP`; the error code pushed by the `#CP` is authoritative if available.
+	#[inline(always)]
+	pub fn is_control_protection_fault_a_shadow_stack_violation(&self) -> bool
+	{
+		self.is_shadow_stack_enabled() && !self.user_control.tracker_is_waiting_for_end_branch()
+	}
+}
@@ -0,0 +1,34 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// A shadow stack pointer, `SSP`, as held in one of the MSRs `IA32_PL0_SSP` to `IA32_PL3_SSP` inclusive.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ShadowStackPointer(u64);
+
+impl ShadowStackPointer
+{
+	/// Creates a new instance from a linear address.
+	#[inline(always)]
+	pub const fn new(linear_address: u64) -> Self
+	{
+		Self(linear_address)
+	}
+
+	/// Linear address of the top of the shadow stack.
+	#[inline(always)]
+	pub fn linear_address(self) -> u64
+	{
+		self.0
+	}
+
+	/// Is this pointer null?
+	///
+	/// A null pointer is the initial value, and is typical when no shadow stack has been allocated for the privilege level.
+	#[inline(always)]
+	pub fn is_null(self) -> bool
+	{
+		self.0 == 0
+	}
+}
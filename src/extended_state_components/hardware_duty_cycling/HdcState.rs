@@ -0,0 +1,50 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The hardware duty cycling MSR `IA32_PM_CTL1` (`HDC` state).
+///
+/// See Section 13.5.8 (HDC State) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture) and Section 15.5 (Hardware Duty Cycling) of Volume 3B.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct HdcState(u64);
+
+impl HdcState
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u64) -> Self
+	{
+		Self(value)
+	}
+
+	/// Raw value of `IA32_PM_CTL1`.
+	#[inline(always)]
+	pub fn value(self) -> u64
+	{
+		self.0
+	}
+
+	/// Hardware duty cycling is allowed to put this logical processor into forced idle, `HDC_Allow_Block`.
+	///
+	/// When clear (`false`), this logical processor is blocked from participating in hardware duty cycling.
+	#[inline(always)]
+	pub fn hdc_allow_block(self) -> bool
+	{
+		self.0 & 0b1 != 0
+	}
+
+	/// Sets `HDC_Allow_Block`.
+	#[inline(always)]
+	pub fn set_hdc_allow_block(&mut self, hdc_allow_block: bool)
+	{
+		if hdc_allow_block
+		{
+			self.0 |= 0b1
+		}
+		else
+		{
+			self.0 &= !0b1
+		}
+	}
+}
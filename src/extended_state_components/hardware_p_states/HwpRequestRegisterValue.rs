@@ -0,0 +1,156 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Value of a hardware-controlled performance states request MSR, either `IA32_HWP_REQUEST` (logical processor) or `IA32_HWP_REQUEST_PKG` (package).
+///
+/// See Section 15.4.4 (Managing HWP) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 3B.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct HwpRequestRegisterValue(u64);
+
+impl HwpRequestRegisterValue
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u64) -> Self
+	{
+		Self(value)
+	}
+
+	/// Raw value.
+	#[inline(always)]
+	pub fn value(self) -> u64
+	{
+		self.0
+	}
+
+	/// Minimum performance hint, `Minimum_Performance`.
+	#[inline(always)]
+	pub fn minimum_performance(self) -> u8
+	{
+		self.byte(0)
+	}
+
+	/// Maximum performance hint, `Maximum_Performance`.
+	#[inline(always)]
+	pub fn maximum_performance(self) -> u8
+	{
+		self.byte(8)
+	}
+
+	/// Desired performance hint, `Desired_Performance`.
+	///
+	/// Zero means hardware autonomous selection.
+	#[inline(always)]
+	pub fn desired_performance(self) -> u8
+	{
+		self.byte(16)
+	}
+
+	/// Energy performance preference hint, `Energy_Performance_Preference` (`EPP`).
+	///
+	/// `0` favours performance, `255` favours energy efficiency.
+	#[inline(always)]
+	pub fn energy_performance_preference(self) -> u8
+	{
+		self.byte(24)
+	}
+
+	/// Activity window, `Activity_Window`, as a 10-bit (`u10`) encoded value.
+	///
+	/// Use `activity_window_microseconds()` to decode it.
+	#[inline(always)]
+	pub fn activity_window(self) -> u16
+	{
+		((self.0 >> 32) & 0x3FF) as u16
+	}
+
+	/// Activity window in microseconds (the 7-bit mantissa multiplied by ten raised to the power of the 3-bit exponent).
+	///
+	/// Zero means hardware autonomous selection.
+	#[inline(always)]
+	pub fn activity_window_microseconds(self) -> u32
+	{
+		let activity_window = self.activity_window();
+		let mantissa = (activity_window & 0x7F) as u32;
+		let exponent = (activity_window >> 7) as u32;
+		mantissa * 10u32.pow(exponent)
+	}
+
+	/// When set (`true`), the package-level request (`IA32_HWP_REQUEST_PKG`) is used in place of this request, `Package_Control`.
+	#[inline(always)]
+	pub fn package_control(self) -> bool
+	{
+		self.bit_set(42)
+	}
+
+	/// Sets the minimum performance hint, `Minimum_Performance`.
+	#[inline(always)]
+	pub fn set_minimum_performance(&mut self, minimum_performance: u8)
+	{
+		self.set_byte(0, minimum_performance)
+	}
+
+	/// Sets the maximum performance hint, `Maximum_Performance`.
+	#[inline(always)]
+	pub fn set_maximum_performance(&mut self, maximum_performance: u8)
+	{
+		self.set_byte(8, maximum_performance)
+	}
+
+	/// Sets the desired performance hint, `Desired_Performance`.
+	#[inline(always)]
+	pub fn set_desired_performance(&mut self, desired_performance: u8)
+	{
+		self.set_byte(16, desired_performance)
+	}
+
+	/// Sets the energy performance preference hint, `Energy_Performance_Preference` (`EPP`).
+	#[inline(always)]
+	pub fn set_energy_performance_preference(&mut self, energy_performance_preference: u8)
+	{
+		self.set_byte(24, energy_performance_preference)
+	}
+
+	/// Sets the activity window, `Activity_Window`, as a 10-bit (`u10`) encoded value.
+	#[inline(always)]
+	pub fn set_activity_window(&mut self, activity_window: u16)
+	{
+		assert!(activity_window <= 0x3FF, "activity_window `{}` is not a 10-bit value", activity_window);
+
+		self.0 = (self.0 & !(0x3FF << 32)) | (((activity_window & 0x3FF) as u64) << 32)
+	}
+
+	/// Sets `Package_Control`.
+	#[inline(always)]
+	pub fn set_package_control(&mut self, package_control: bool)
+	{
+		if package_control
+		{
+			self.0 |= 1 << 42
+		}
+		else
+		{
+			self.0 &= !(1 << 42)
+		}
+	}
+
+	#[inline(always)]
+	fn byte(self, shift: u64) -> u8
+	{
+		(self.0 >> shift) as u8
+	}
+
+	#[inline(always)]
+	fn set_byte(&mut self, shift: u64, byte: u8)
+	{
+		self.0 = (self.0 & !(0xFF << shift)) | ((byte as u64) << shift)
+	}
+
+	#[inline(always)]
+	fn bit_set(self, bit: u64) -> bool
+	{
+		self.0 & (1 << bit) != 0
+	}
+}
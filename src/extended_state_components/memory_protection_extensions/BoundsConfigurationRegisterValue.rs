@@ -0,0 +1,54 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Value of a `MPX` configuration register, either `BNDCFGU` (user mode) or `IA32_BNDCFGS` (supervisor mode).
+///
+/// See Section 17.3.3 (Configuration and Status Registers) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture), as it was before `MPX` was removed.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct BoundsConfigurationRegisterValue(u64);
+
+impl BoundsConfigurationRegisterValue
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u64) -> Self
+	{
+		Self(value)
+	}
+
+	/// Raw value.
+	#[inline(always)]
+	pub fn value(self) -> u64
+	{
+		self.0
+	}
+
+	/// Enable, `EN`.
+	///
+	/// When set (`true`), `MPX` bounds checking and bound directory operations are enabled.
+	#[inline(always)]
+	pub fn enable(self) -> bool
+	{
+		self.0 & 0b01 != 0
+	}
+
+	/// Bound preserve, `BNDPRESERVE`.
+	///
+	/// When clear (`false`), a near `CALL`, `RET` or `JMP` (or `Jcc`) without the `BND` (`F2H`) prefix initializes all bounds registers.
+	#[inline(always)]
+	pub fn bound_preserve(self) -> bool
+	{
+		self.0 & 0b10 != 0
+	}
+
+	/// Linear address of the base of the bound directory, `BDBASE`.
+	///
+	/// Always 4KB aligned.
+	#[inline(always)]
+	pub fn bound_directory_base(self) -> u64
+	{
+		self.0 & !0xFFF
+	}
+}
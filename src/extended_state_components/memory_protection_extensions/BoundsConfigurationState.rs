@@ -0,0 +1,15 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The `MPX` user-mode configuration register `BNDCFGU` and status register `BNDSTATUS` (`BNDCSR` state).
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct BoundsConfigurationState
+{
+	/// Value of the user-mode configuration register `BNDCFGU`.
+	pub user_configuration: BoundsConfigurationRegisterValue,
+
+	/// Value of the status register `BNDSTATUS`.
+	pub status: BoundsStatusRegisterValue,
+}
@@ -0,0 +1,52 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// A 128-bit `MPX` bounds register, `BNDn`.
+///
+/// The upper bound is stored in one's complement form, so that the initial value (all zeros) represents bounds that permit access to the entire address space.
+///
+/// See Section 17.3.1 (Bounds Registers) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture), as it was before `MPX` was removed.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct BoundsRegister
+{
+	/// Lower bound, `LB`.
+	pub lower_bound: u64,
+
+	/// Upper bound, `UB`, in one's complement form.
+	///
+	/// Use `upper_bound()` to obtain the real upper bound.
+	pub upper_bound_ones_complement: u64,
+}
+
+impl BoundsRegister
+{
+	/// Real upper bound (inclusive).
+	#[inline(always)]
+	pub fn upper_bound(&self) -> u64
+	{
+		!self.upper_bound_ones_complement
+	}
+
+	/// Sets the real upper bound (inclusive).
+	#[inline(always)]
+	pub fn set_upper_bound(&mut self, upper_bound: u64)
+	{
+		self.upper_bound_ones_complement = !upper_bound
+	}
+
+	/// Is this bounds register in its initial state (ie permitting access to the entire address space)?
+	#[inline(always)]
+	pub fn is_initial(&self) -> bool
+	{
+		self.lower_bound == 0 && self.upper_bound_ones_complement == 0
+	}
+
+	/// Would `BNDCL` and `BNDCU` permit `address`?
+	#[inline(always)]
+	pub fn permits(&self, address: u64) -> bool
+	{
+		address >= self.lower_bound && address <= self.upper_bound()
+	}
+}
@@ -0,0 +1,56 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The four `MPX` bounds registers `BND0`–`BND3` (`BNDREGS` state).
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct BoundsRegisterState
+{
+	/// Bounds register `BND0`.
+	pub bnd0: BoundsRegister,
+
+	/// Bounds register `BND1`.
+	pub bnd1: BoundsRegister,
+
+	/// Bounds register `BND2`.
+	pub bnd2: BoundsRegister,
+
+	/// Bounds register `BND3`.
+	pub bnd3: BoundsRegister,
+}
+
+impl BoundsRegisterState
+{
+	/// Bounds register `BNDn`.
+	///
+	/// `n` must be less than 4.
+	#[inline(always)]
+	pub fn bnd(&self, n: u8) -> &BoundsRegister
+	{
+		match n
+		{
+			0 => &self.bnd0,
+			1 => &self.bnd1,
+			2 => &self.bnd2,
+			3 => &self.bnd3,
+			_ => panic!("n `{}` is not less than 4", n),
+		}
+	}
+
+	/// Bounds register `BNDn`.
+	///
+	/// `n` must be less than 4.
+	#[inline(always)]
+	pub fn bnd_mut(&mut self, n: u8) -> &mut BoundsRegister
+	{
+		match n
+		{
+			0 => &mut self.bnd0,
+			1 => &mut self.bnd1,
+			2 => &mut self.bnd2,
+			3 => &mut self.bnd3,
+			_ => panic!("n `{}` is not less than 4", n),
+		}
+	}
+}
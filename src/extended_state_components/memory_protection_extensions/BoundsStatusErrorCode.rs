@@ -0,0 +1,21 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Error code, `EC`, in the `MPX` status register `BNDSTATUS`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum BoundsStatusErrorCode
+{
+	/// No exception.
+	NoException = 0b00,
+
+	/// Bounds violation (by `BNDCL`, `BNDCU` or `BNDCN`).
+	BoundsViolation = 0b01,
+
+	/// Invalid bound directory entry (by `BNDLDX` or `BNDSTX`).
+	InvalidBoundDirectoryEntry = 0b10,
+
+	/// Reserved.
+	Reserved = 0b11,
+}
@@ -0,0 +1,45 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Value of the `MPX` status register `BNDSTATUS`.
+///
+/// Only meaningful after a bound range exceeded exception (`#BR`) caused by a `MPX` instruction.
+///
+/// See Section 17.3.3 (Configuration and Status Registers) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture), as it was before `MPX` was removed.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct BoundsStatusRegisterValue(u64);
+
+impl BoundsStatusRegisterValue
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u64) -> Self
+	{
+		Self(value)
+	}
+
+	/// Raw value.
+	#[inline(always)]
+	pub fn value(self) -> u64
+	{
+		self.0
+	}
+
+	/// Error code, `EC`.
+	#[inline(always)]
+	pub fn error_code(self) -> BoundsStatusErrorCode
+	{
+		unsafe { transmute((self.0 & 0b11) as u8) }
+	}
+
+	/// Linear address of the bound directory entry that caused the failure, `ABD`.
+	///
+	/// Only meaningful if `error_code()` is `BoundsStatusErrorCode::InvalidBoundDirectoryEntry`.
+	#[inline(always)]
+	pub fn bound_directory_entry_address(self) -> u64
+	{
+		self.0 & !0b11
+	}
+}
@@ -0,0 +1,57 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+use super::*;
+
+
+/// Intel® Advanced Matrix Extensions (`AMX`) tile configuration and tile data.
+pub mod advanced_matrix_extensions;
+
+
+/// Intel® Advanced Performance Extensions (`APX`) extended general purpose registers.
+pub mod advanced_performance_extensions;
+
+
+/// AMD Lightweight Profiling (`LWP` state).
+pub mod amd_lightweight_profiling;
+
+
+/// Architectural last branch records (`LBR` state).
+pub mod architectural_last_branch_records;
+
+
+/// Intel® Advanced Vector Extensions 512 (`AVX-512`) `opmask` state.
+pub mod avx_512;
+
+
+/// Intel® Control-flow Enforcement Technology (`CET`) shadow stack and indirect branch tracking state.
+pub mod control_flow_enforcement_technology;
+
+
+/// Hardware duty cycling (`HDC` state).
+pub mod hardware_duty_cycling;
+
+
+/// Hardware-controlled performance states (`HWP` state).
+pub mod hardware_p_states;
+
+
+/// Intel® Memory Protection Extensions (`MPX`) bounds registers, configuration and status.
+pub mod memory_protection_extensions;
+
+
+/// Process address space identifier (`PASID` state).
+pub mod process_address_space_identifier;
+
+
+/// Intel Processor Trace (`PT` state).
+pub mod processor_trace;
+
+
+/// Protection keys for user-mode pages (`PKRU` state).
+pub mod protection_keys;
+
+
+/// User interrupts (`UINTR` state).
+pub mod user_interrupts;
@@ -0,0 +1,45 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The process address space identifier MSR `IA32_PASID` (`PASID` state).
+///
+/// Used by `ENQCMD` to identify the address space of work submitted to a shared device.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct PasidState(u64);
+
+impl PasidState
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u64) -> Self
+	{
+		Self(value)
+	}
+
+	/// Raw value of `IA32_PASID`.
+	#[inline(always)]
+	pub fn value(self) -> u64
+	{
+		self.0
+	}
+
+	/// Process address space identifier, `PASID`.
+	///
+	/// A 20-bit (`u20`) value.
+	#[inline(always)]
+	pub fn process_address_space_identifier(self) -> u32
+	{
+		(self.0 & 0xF_FFFF) as u32
+	}
+
+	/// Is the process address space identifier valid?
+	///
+	/// If not (`false`), `ENQCMD` causes a general-protection fault (`#GP`).
+	#[inline(always)]
+	pub fn valid(self) -> bool
+	{
+		self.0 & (1 << 31) != 0
+	}
+}
@@ -0,0 +1,54 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Configuration of an Intel Processor Trace address range, `ADDRn_CFG`, in the `IA32_RTIT_CTL` MSR.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AddressRangeConfiguration
+{
+	/// The address range is not used.
+	NotUsed,
+
+	/// Tracing is only enabled within the address range (`FilterEn`).
+	FilterEn,
+
+	/// Tracing is stopped when executing within the address range (`TraceStop`).
+	TraceStop,
+
+	/// A reserved value (`3` to `15` inclusive).
+	Reserved(u8),
+}
+
+impl From<u8> for AddressRangeConfiguration
+{
+	#[inline(always)]
+	fn from(value: u8) -> Self
+	{
+		use self::AddressRangeConfiguration::*;
+
+		match value & 0b1111
+		{
+			0 => NotUsed,
+			1 => FilterEn,
+			2 => TraceStop,
+			reserved => Reserved(reserved),
+		}
+	}
+}
+
+impl From<AddressRangeConfiguration> for u8
+{
+	#[inline(always)]
+	fn from(value: AddressRangeConfiguration) -> Self
+	{
+		use self::AddressRangeConfiguration::*;
+
+		match value
+		{
+			NotUsed => 0,
+			FilterEn => 1,
+			TraceStop => 2,
+			Reserved(reserved) => reserved & 0b1111,
+		}
+	}
+}
@@ -0,0 +1,241 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Intel Processor Trace MSRs (`PT` state).
+///
+/// The layout is documented in Section 13.5.6 (PT State) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture).
+/// The state component always has space for the first two address ranges; it has space for the third and fourth only if the processor supports them, so this is a view over bytes rather than a fixed-size structure.
+///
+/// `B` is either `&[u8]` (read only) or `&mut [u8]` (read and write).
+#[derive(Copy, Clone)]
+pub struct ProcessorTraceState<B: AsRef<[u8]>>
+{
+	bytes: B,
+
+	number_of_address_ranges: u8,
+}
+
+impl<B: AsRef<[u8]>> Debug for ProcessorTraceState<B>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		let mut debug_struct = f.debug_struct("ProcessorTraceState");
+		debug_struct.field("IA32_RTIT_CTL", &self.control());
+		debug_struct.field("IA32_RTIT_OUTPUT_BASE", &self.output_base());
+		debug_struct.field("IA32_RTIT_OUTPUT_MASK_PTRS", &self.output_mask_pointers());
+		debug_struct.field("IA32_RTIT_STATUS", &self.status());
+		debug_struct.field("IA32_RTIT_CR3_MATCH", &self.cr3_match());
+		for n in 0 .. self.number_of_address_ranges()
+		{
+			debug_struct.field(["IA32_RTIT_ADDR0_A/B", "IA32_RTIT_ADDR1_A/B", "IA32_RTIT_ADDR2_A/B", "IA32_RTIT_ADDR3_A/B"][n as usize], &self.address_range(n));
+		}
+		debug_struct.finish()
+	}
+}
+
+impl<B: AsRef<[u8]>> ProcessorTraceState<B>
+{
+	const IA32_RTIT_CTL: usize = 0;
+
+	const IA32_RTIT_OUTPUT_BASE: usize = 8;
+
+	const IA32_RTIT_OUTPUT_MASK_PTRS: usize = 16;
+
+	const IA32_RTIT_STATUS: usize = 24;
+
+	const IA32_RTIT_CR3_MATCH: usize = 32;
+
+	const IA32_RTIT_ADDR0_A: usize = 40;
+
+	/// The maximum number of address ranges held in the state component.
+	pub const MaximumNumberOfAddressRanges: u8 = 4;
+
+	/// Creates a new instance.
+	///
+	/// `number_of_address_ranges` is the number of address ranges the processor that saved the state supports (see `number_of_address_ranges_of_current_processor()`) and must be no more than `MaximumNumberOfAddressRanges`.
+	/// `bytes` must be at least 72 bytes long (ie have space for at least two address ranges) and have space for `number_of_address_ranges`.
+	#[inline(always)]
+	pub fn new(bytes: B, number_of_address_ranges: u8) -> Self
+	{
+		assert!(number_of_address_ranges <= Self::MaximumNumberOfAddressRanges, "number_of_address_ranges `{}` is more than 4", number_of_address_ranges);
+		let minimum_length = Self::address_range_offset(max(2, number_of_address_ranges));
+		assert!(bytes.as_ref().len() >= minimum_length, "bytes are too short at `{}` bytes; at least `{}` are needed", bytes.as_ref().len(), minimum_length);
+
+		Self
+		{
+			bytes,
+			number_of_address_ranges,
+		}
+	}
+
+	/// Number of address ranges supported by the current processor, from `CPUID.(EAX=14H,ECX=1):EAX[2:0]`, but no more than `MaximumNumberOfAddressRanges`.
+	///
+	/// Returns `0` if the processor does not support Intel Processor Trace.
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	#[inline(always)]
+	pub fn number_of_address_ranges_of_current_processor() -> u8
+	{
+		const EAX_INTEL_PROCESSOR_TRACE_ENUMERATION: u32 = 0x14;
+
+		if !has_cpuid()
+		{
+			return 0
+		}
+
+		let maximum_eax_value = unsafe { __cpuid(0) }.eax;
+		if maximum_eax_value < EAX_INTEL_PROCESSOR_TRACE_ENUMERATION
+		{
+			return 0
+		}
+
+		let maximum_sub_leaf = unsafe { __cpuid_count(EAX_INTEL_PROCESSOR_TRACE_ENUMERATION, 0) }.eax;
+		if maximum_sub_leaf < 1
+		{
+			return 0
+		}
+
+		let number_of_address_ranges = (unsafe { __cpuid_count(EAX_INTEL_PROCESSOR_TRACE_ENUMERATION, 1) }.eax & 0b111) as u8;
+		min(number_of_address_ranges, Self::MaximumNumberOfAddressRanges)
+	}
+
+	/// Value of the `IA32_RTIT_CTL` MSR.
+	#[inline(always)]
+	pub fn control(&self) -> RtitControlRegisterValue
+	{
+		RtitControlRegisterValue::new(self.read(Self::IA32_RTIT_CTL))
+	}
+
+	/// Value of the `IA32_RTIT_OUTPUT_BASE` MSR.
+	///
+	/// The physical address of the output region or of the first Table of Physical Addresses (`ToPA`).
+	#[inline(always)]
+	pub fn output_base(&self) -> u64
+	{
+		self.read(Self::IA32_RTIT_OUTPUT_BASE)
+	}
+
+	/// Value of the `IA32_RTIT_OUTPUT_MASK_PTRS` MSR.
+	#[inline(always)]
+	pub fn output_mask_pointers(&self) -> u64
+	{
+		self.read(Self::IA32_RTIT_OUTPUT_MASK_PTRS)
+	}
+
+	/// Offset into the current output region, `OutputOffset` (bits 63:32 of `IA32_RTIT_OUTPUT_MASK_PTRS`).
+	#[inline(always)]
+	pub fn output_offset(&self) -> u32
+	{
+		(self.output_mask_pointers() >> 32) as u32
+	}
+
+	/// Value of the `IA32_RTIT_STATUS` MSR.
+	#[inline(always)]
+	pub fn status(&self) -> RtitStatusRegisterValue
+	{
+		RtitStatusRegisterValue::new(self.read(Self::IA32_RTIT_STATUS))
+	}
+
+	/// Value of the `IA32_RTIT_CR3_MATCH` MSR.
+	#[inline(always)]
+	pub fn cr3_match(&self) -> u64
+	{
+		self.read(Self::IA32_RTIT_CR3_MATCH)
+	}
+
+	/// Number of address ranges supported, as given to `new()`.
+	#[inline(always)]
+	pub fn number_of_address_ranges(&self) -> u8
+	{
+		self.number_of_address_ranges
+	}
+
+	/// Values of the `IA32_RTIT_ADDRn_A` (start) and `IA32_RTIT_ADDRn_B` (end) MSRs.
+	///
+	/// Returns `None` if address range `n` is not supported.
+	#[inline(always)]
+	pub fn address_range(&self, n: u8) -> Option<(u64, u64)>
+	{
+		if n >= self.number_of_address_ranges()
+		{
+			return None
+		}
+
+		let offset = Self::address_range_offset(n);
+		Some((self.read(offset), self.read(offset + 8)))
+	}
+
+	#[inline(always)]
+	fn address_range_offset(n: u8) -> usize
+	{
+		Self::IA32_RTIT_ADDR0_A + (n as usize) * 16
+	}
+
+	#[inline(always)]
+	fn read(&self, offset: usize) -> u64
+	{
+		u64::from_le_bytes(*array_ref!(self.bytes.as_ref(), offset, 8))
+	}
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> ProcessorTraceState<B>
+{
+	/// Sets the value of the `IA32_RTIT_CTL` MSR.
+	#[inline(always)]
+	pub fn set_control(&mut self, control: RtitControlRegisterValue)
+	{
+		self.write(Self::IA32_RTIT_CTL, control.value())
+	}
+
+	/// Sets the value of the `IA32_RTIT_OUTPUT_BASE` MSR.
+	#[inline(always)]
+	pub fn set_output_base(&mut self, output_base: u64)
+	{
+		self.write(Self::IA32_RTIT_OUTPUT_BASE, output_base)
+	}
+
+	/// Sets the value of the `IA32_RTIT_OUTPUT_MASK_PTRS` MSR.
+	#[inline(always)]
+	pub fn set_output_mask_pointers(&mut self, output_mask_pointers: u64)
+	{
+		self.write(Self::IA32_RTIT_OUTPUT_MASK_PTRS, output_mask_pointers)
+	}
+
+	/// Sets the value of the `IA32_RTIT_STATUS` MSR.
+	#[inline(always)]
+	pub fn set_status(&mut self, status: RtitStatusRegisterValue)
+	{
+		self.write(Self::IA32_RTIT_STATUS, status.value())
+	}
+
+	/// Sets the value of the `IA32_RTIT_CR3_MATCH` MSR.
+	#[inline(always)]
+	pub fn set_cr3_match(&mut self, cr3_match: u64)
+	{
+		self.write(Self::IA32_RTIT_CR3_MATCH, cr3_match)
+	}
+
+	/// Sets the values of the `IA32_RTIT_ADDRn_A` (start) and `IA32_RTIT_ADDRn_B` (end) MSRs.
+	///
+	/// Returns `false` if address range `n` is not supported.
+	#[inline(always)]
+	pub fn set_address_range(&mut self, n: u8, start: u64, end: u64) -> bool
+	{
+		if n >= self.number_of_address_ranges()
+		{
+			return false
+		}
+
+		let offset = Self::address_range_offset(n);
+		self.write(offset, start);
+		self.write(offset + 8, end);
+		true
+	}
+
+	#[inline(always)]
+	fn write(&mut self, offset: usize, value: u64)
+	{
+		*array_mut_ref!(self.bytes.as_mut(), offset, 8) = value.to_le_bytes()
+	}
+}
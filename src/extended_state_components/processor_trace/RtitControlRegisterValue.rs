@@ -0,0 +1,257 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Value of the Intel Processor Trace control MSR, `IA32_RTIT_CTL`.
+///
+/// See Section 33.2.8 (Trace Configuration MSRs) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 3C.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct RtitControlRegisterValue(u64);
+
+impl RtitControlRegisterValue
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u64) -> Self
+	{
+		Self(value)
+	}
+
+	/// Raw value.
+	#[inline(always)]
+	pub fn value(self) -> u64
+	{
+		self.0
+	}
+
+	/// Tracing enabled, `TraceEn`.
+	#[inline(always)]
+	pub fn trace_enable(self) -> bool
+	{
+		self.bit_set(0)
+	}
+
+	/// Cycle-accurate mode (`CYC` packets) enabled, `CYCEn`.
+	#[inline(always)]
+	pub fn cycle_enable(self) -> bool
+	{
+		self.bit_set(1)
+	}
+
+	/// Trace when `CPL = 0`, `OS`.
+	#[inline(always)]
+	pub fn os(self) -> bool
+	{
+		self.bit_set(2)
+	}
+
+	/// Trace when `CPL > 0`, `User`.
+	#[inline(always)]
+	pub fn user(self) -> bool
+	{
+		self.bit_set(3)
+	}
+
+	/// Power event trace packets enabled, `PwrEvtEn`.
+	#[inline(always)]
+	pub fn power_event_enable(self) -> bool
+	{
+		self.bit_set(4)
+	}
+
+	/// `FUP` packets after `PTWRITE` enabled, `FUPonPTW`.
+	#[inline(always)]
+	pub fn fup_on_ptwrite(self) -> bool
+	{
+		self.bit_set(5)
+	}
+
+	/// Trace output is directed to the trace transport subsystem rather than memory, `FabricEn`.
+	#[inline(always)]
+	pub fn fabric_enable(self) -> bool
+	{
+		self.bit_set(6)
+	}
+
+	/// `CR3` filtering enabled, `CR3Filter`.
+	#[inline(always)]
+	pub fn cr3_filter(self) -> bool
+	{
+		self.bit_set(7)
+	}
+
+	/// Output uses a Table of Physical Addresses, `ToPA`, rather than a single contiguous range.
+	#[inline(always)]
+	pub fn table_of_physical_addresses(self) -> bool
+	{
+		self.bit_set(8)
+	}
+
+	/// `MTC` packets enabled, `MTCEn`.
+	#[inline(always)]
+	pub fn mtc_enable(self) -> bool
+	{
+		self.bit_set(9)
+	}
+
+	/// `TSC` packets enabled, `TSCEn`.
+	#[inline(always)]
+	pub fn tsc_enable(self) -> bool
+	{
+		self.bit_set(10)
+	}
+
+	/// Return compression disabled, `DisRETC`.
+	#[inline(always)]
+	pub fn disable_return_compression(self) -> bool
+	{
+		self.bit_set(11)
+	}
+
+	/// `PTWRITE` packets enabled, `PTWEn`.
+	#[inline(always)]
+	pub fn ptwrite_enable(self) -> bool
+	{
+		self.bit_set(12)
+	}
+
+	/// Change of flow (branch) packets enabled, `BranchEn`.
+	#[inline(always)]
+	pub fn branch_enable(self) -> bool
+	{
+		self.bit_set(13)
+	}
+
+	/// `MTC` packet frequency, `MTCFreq`.
+	///
+	/// A 4-bit (`u4`) value.
+	#[inline(always)]
+	pub fn mtc_frequency(self) -> u8
+	{
+		((self.0 >> 14) & 0b1111) as u8
+	}
+
+	/// `CYC` packet threshold, `CycThresh`.
+	///
+	/// A 4-bit (`u4`) value.
+	#[inline(always)]
+	pub fn cycle_threshold(self) -> u8
+	{
+		((self.0 >> 19) & 0b1111) as u8
+	}
+
+	/// `PSB` packet frequency, `PSBFreq`.
+	///
+	/// A 4-bit (`u4`) value.
+	#[inline(always)]
+	pub fn psb_frequency(self) -> u8
+	{
+		((self.0 >> 24) & 0b1111) as u8
+	}
+
+	/// Event trace packets enabled, `EventEn`.
+	#[inline(always)]
+	pub fn event_enable(self) -> bool
+	{
+		self.bit_set(31)
+	}
+
+	/// Configuration of address range `n`, `ADDRn_CFG`.
+	///
+	/// `n` must be less than 4.
+	#[inline(always)]
+	pub fn address_range_configuration(self, n: u8) -> AddressRangeConfiguration
+	{
+		assert!(n < 4, "n `{}` is not less than 4", n);
+
+		AddressRangeConfiguration::from(((self.0 >> Self::address_range_configuration_shift(n)) & 0b1111) as u8)
+	}
+
+	/// `TNT` packets disabled, `DisTNT`.
+	#[inline(always)]
+	pub fn disable_tnt(self) -> bool
+	{
+		self.bit_set(55)
+	}
+
+	/// Sets tracing enabled, `TraceEn`.
+	#[inline(always)]
+	pub fn set_trace_enable(&mut self, trace_enable: bool)
+	{
+		self.set_bit(0, trace_enable)
+	}
+
+	/// Sets trace when `CPL = 0`, `OS`.
+	#[inline(always)]
+	pub fn set_os(&mut self, os: bool)
+	{
+		self.set_bit(2, os)
+	}
+
+	/// Sets trace when `CPL > 0`, `User`.
+	#[inline(always)]
+	pub fn set_user(&mut self, user: bool)
+	{
+		self.set_bit(3, user)
+	}
+
+	/// Sets output uses a Table of Physical Addresses, `ToPA`.
+	#[inline(always)]
+	pub fn set_table_of_physical_addresses(&mut self, table_of_physical_addresses: bool)
+	{
+		self.set_bit(8, table_of_physical_addresses)
+	}
+
+	/// Sets `TSC` packets enabled, `TSCEn`.
+	#[inline(always)]
+	pub fn set_tsc_enable(&mut self, tsc_enable: bool)
+	{
+		self.set_bit(10, tsc_enable)
+	}
+
+	/// Sets change of flow (branch) packets enabled, `BranchEn`.
+	#[inline(always)]
+	pub fn set_branch_enable(&mut self, branch_enable: bool)
+	{
+		self.set_bit(13, branch_enable)
+	}
+
+	/// Sets the configuration of address range `n`, `ADDRn_CFG`.
+	///
+	/// `n` must be less than 4.
+	#[inline(always)]
+	pub fn set_address_range_configuration(&mut self, n: u8, address_range_configuration: AddressRangeConfiguration)
+	{
+		assert!(n < 4, "n `{}` is not less than 4", n);
+
+		let shift = Self::address_range_configuration_shift(n);
+		let value: u8 = address_range_configuration.into();
+		self.0 = (self.0 & !(0b1111 << shift)) | ((value as u64) << shift)
+	}
+
+	#[inline(always)]
+	fn address_range_configuration_shift(n: u8) -> u64
+	{
+		32 + (n as u64) * 4
+	}
+
+	#[inline(always)]
+	fn bit_set(self, bit: u64) -> bool
+	{
+		self.0 & (1 << bit) != 0
+	}
+
+	#[inline(always)]
+	fn set_bit(&mut self, bit: u64, set: bool)
+	{
+		if set
+		{
+			self.0 |= 1 << bit
+		}
+		else
+		{
+			self.0 &= !(1 << bit)
+		}
+	}
+}
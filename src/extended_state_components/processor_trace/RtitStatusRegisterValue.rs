@@ -0,0 +1,85 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Value of the Intel Processor Trace status MSR, `IA32_RTIT_STATUS`.
+///
+/// See Section 33.2.8 (Trace Configuration MSRs) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 3C.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct RtitStatusRegisterValue(u64);
+
+impl RtitStatusRegisterValue
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u64) -> Self
+	{
+		Self(value)
+	}
+
+	/// Raw value.
+	#[inline(always)]
+	pub fn value(self) -> u64
+	{
+		self.0
+	}
+
+	/// Tracing is within an address range configured as `FilterEn`, `FilterEn`.
+	#[inline(always)]
+	pub fn filter_enable(self) -> bool
+	{
+		self.0 & (1 << 0) != 0
+	}
+
+	/// Tracing is allowed by the current `CPL` and `CR3` filtering, `ContextEn`.
+	#[inline(always)]
+	pub fn context_enable(self) -> bool
+	{
+		self.0 & (1 << 1) != 0
+	}
+
+	/// Tracing is triggered, `TriggerEn`.
+	#[inline(always)]
+	pub fn trigger_enable(self) -> bool
+	{
+		self.0 & (1 << 2) != 0
+	}
+
+	/// An operational error has occurred, `Error`.
+	#[inline(always)]
+	pub fn error(self) -> bool
+	{
+		self.0 & (1 << 4) != 0
+	}
+
+	/// The output region is full and tracing has stopped, `Stopped`.
+	#[inline(always)]
+	pub fn stopped(self) -> bool
+	{
+		self.0 & (1 << 5) != 0
+	}
+
+	/// A `PSB` packet is pending, `PendPSB`.
+	#[inline(always)]
+	pub fn pending_psb(self) -> bool
+	{
+		self.0 & (1 << 6) != 0
+	}
+
+	/// A `ToPA` performance monitoring interrupt is pending, `PendTopaPMI`.
+	#[inline(always)]
+	pub fn pending_topa_pmi(self) -> bool
+	{
+		self.0 & (1 << 7) != 0
+	}
+
+	/// Number of packet bytes written since the last `PSB` packet, `PacketByteCnt`.
+	///
+	/// A 17-bit (`u17`) value.
+	#[inline(always)]
+	pub fn packet_byte_count(self) -> u32
+	{
+		((self.0 >> 32) & 0x1_FFFF) as u32
+	}
+}
@@ -0,0 +1,115 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The 32-bit protection key rights register for user pages, `PKRU` (`PKRU` state).
+///
+/// For each of the 16 protection keys, `0` to `15` inclusive, there is an access-disable bit, `ADi` (bit `2i`), and a write-disable bit, `WDi` (bit `2i + 1`).
+///
+/// See Section 4.6.2 (Protection Keys) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 3A.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct PkruRegisterValue(u32);
+
+impl PkruRegisterValue
+{
+	/// Number of protection keys.
+	pub const NumberOfProtectionKeys: u8 = 16;
+
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u32) -> Self
+	{
+		Self(value)
+	}
+
+	/// Raw value.
+	#[inline(always)]
+	pub fn value(self) -> u32
+	{
+		self.0
+	}
+
+	/// Access-disable, `ADi`, for protection key `key`.
+	///
+	/// When set (`true`), user-mode data accesses to pages with this protection key are disallowed.
+	///
+	/// `key` must be less than 16.
+	#[inline(always)]
+	pub fn access_disable(self, key: u8) -> bool
+	{
+		self.bit_set(Self::access_disable_bit(key))
+	}
+
+	/// Write-disable, `WDi`, for protection key `key`.
+	///
+	/// When set (`true`), user-mode writes (and, if `CR0.WP` is set, supervisor-mode writes) to pages with this protection key are disallowed.
+	///
+	/// `key` must be less than 16.
+	#[inline(always)]
+	pub fn write_disable(self, key: u8) -> bool
+	{
+		self.bit_set(Self::write_disable_bit(key))
+	}
+
+	/// Sets access-disable, `ADi`, for protection key `key`.
+	///
+	/// `key` must be less than 16.
+	#[inline(always)]
+	pub fn set_access_disable(&mut self, key: u8, access_disable: bool)
+	{
+		self.set_bit(Self::access_disable_bit(key), access_disable)
+	}
+
+	/// Sets write-disable, `WDi`, for protection key `key`.
+	///
+	/// `key` must be less than 16.
+	#[inline(always)]
+	pub fn set_write_disable(&mut self, key: u8, write_disable: bool)
+	{
+		self.set_bit(Self::write_disable_bit(key), write_disable)
+	}
+
+	/// Clears both access-disable and write-disable for protection key `key`, so granting all access.
+	///
+	/// `key` must be less than 16.
+	#[inline(always)]
+	pub fn grant_access(&mut self, key: u8)
+	{
+		self.set_access_disable(key, false);
+		self.set_write_disable(key, false)
+	}
+
+	#[inline(always)]
+	fn access_disable_bit(key: u8) -> u32
+	{
+		assert!(key < Self::NumberOfProtectionKeys, "key `{}` is not less than 16", key);
+
+		(key as u32) * 2
+	}
+
+	#[inline(always)]
+	fn write_disable_bit(key: u8) -> u32
+	{
+		Self::access_disable_bit(key) + 1
+	}
+
+	#[inline(always)]
+	fn bit_set(self, bit: u32) -> bool
+	{
+		self.0 & (1 << bit) != 0
+	}
+
+	#[inline(always)]
+	fn set_bit(&mut self, bit: u32, set: bool)
+	{
+		if set
+		{
+			self.0 |= 1 << bit
+		}
+		else
+		{
+			self.0 &= !(1 << bit)
+		}
+	}
+}
@@ -0,0 +1,40 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Restores the previous value of the `PKRU` register when dropped.
+///
+/// Created by `UserModeProtectionKeys.grant_access()`.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[derive(Debug)]
+#[must_use]
+pub struct ProtectionKeyAccessGuard
+{
+	user_mode_protection_keys: UserModeProtectionKeys,
+
+	previous: PkruRegisterValue,
+
+	// `PKRU` is per-thread, so this guard must not move to another thread.
+	not_send: PhantomData<*const ()>,
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl Drop for ProtectionKeyAccessGuard
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.user_mode_protection_keys.wrpkru(self.previous)
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl ProtectionKeyAccessGuard
+{
+	/// Value of the `PKRU` register that will be restored.
+	#[inline(always)]
+	pub fn previous(&self) -> PkruRegisterValue
+	{
+		self.previous
+	}
+}
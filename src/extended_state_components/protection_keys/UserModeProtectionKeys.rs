@@ -0,0 +1,128 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Proof that the operating system has enabled protection keys for user-mode pages (`CPUID.(EAX=07H,ECX=0H):ECX.OSPKE [bit 4]` is set).
+///
+/// The `RDPKRU` and `WRPKRU` instructions cause an invalid-opcode exception (`#UD`) if this is not the case; this type only allows them to be used once this has been checked.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UserModeProtectionKeys(());
+
+impl UserModeProtectionKeys
+{
+	/// Creates a new instance.
+	///
+	/// Returns `None` if the processor does not support protection keys or the operating system has not enabled them (by setting `CR4.PKE`).
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	#[inline(always)]
+	pub fn new() -> Option<Self>
+	{
+		const EAX_STRUCTURED_EXTENDED_FEATURE_FLAGS: u32 = 0x07;
+
+		if !has_cpuid()
+		{
+			return None
+		}
+
+		let maximum_eax_value = unsafe { __cpuid(0) }.eax;
+		if maximum_eax_value < EAX_STRUCTURED_EXTENDED_FEATURE_FLAGS
+		{
+			return None
+		}
+
+		const OSPKE: u32 = 1 << 4;
+		let ecx = unsafe { __cpuid_count(EAX_STRUCTURED_EXTENDED_FEATURE_FLAGS, 0) }.ecx;
+		if ecx & OSPKE != 0
+		{
+			Some(Self(()))
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// Reads the current value of the `PKRU` register.
+	///
+	/// Uses the `RDPKRU` instruction.
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	#[inline(always)]
+	pub fn rdpkru(self) -> PkruRegisterValue
+	{
+		let mut pkru: u32 = unsafe { uninitialized() };
+
+		unsafe
+		{
+			asm!
+			(
+				"rdpkru"
+				:
+					// Output constraints.
+					"={eax}"(pkru)
+				:
+					// Input constraints.
+					"{ecx}"(0u32)
+				:
+					// Clobbers.
+					"edx"
+				:
+					// Options.
+					"volatile"
+			);
+		}
+
+		PkruRegisterValue(pkru)
+	}
+
+	/// Writes the `PKRU` register.
+	///
+	/// Only affects the current thread.
+	///
+	/// Uses the `WRPKRU` instruction.
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	#[inline(always)]
+	pub fn wrpkru(self, pkru: PkruRegisterValue)
+	{
+		unsafe
+		{
+			asm!
+			(
+				"wrpkru"
+				:
+					// Output constraints.
+				:
+					// Input constraints.
+					"{eax}"(pkru.0), "{ecx}"(0u32), "{edx}"(0u32)
+				:
+					// Clobbers.
+					"memory"
+				:
+					// Options.
+					"volatile"
+			);
+		}
+	}
+
+	/// Temporarily grants all access to pages with protection key `key`, restoring the previous value of the `PKRU` register when the returned guard is dropped.
+	///
+	/// Only affects the current thread; the guard must be dropped on the same thread.
+	///
+	/// `key` must be less than 16.
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	#[inline(always)]
+	pub fn grant_access(self, key: u8) -> ProtectionKeyAccessGuard
+	{
+		let previous = self.rdpkru();
+
+		let mut granted = previous;
+		granted.grant_access(key);
+		self.wrpkru(granted);
+
+		ProtectionKeyAccessGuard
+		{
+			user_mode_protection_keys: self,
+			previous,
+			not_send: PhantomData,
+		}
+	}
+}
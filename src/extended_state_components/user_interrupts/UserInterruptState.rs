@@ -0,0 +1,94 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// User interrupt MSRs and the user interrupt flag (`UINTR` state).
+///
+/// See Section 13.5.11 (UINTR State) of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture).
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct UserInterruptState
+{
+	/// Value of the `IA32_UINTR_HANDLER` MSR, `UIHANDLER`.
+	///
+	/// The linear address of the user interrupt handler.
+	pub user_interrupt_handler: u64,
+
+	/// Value of the `IA32_UINTR_STACKADJUST` MSR, `UISTACKADJUST`.
+	///
+	/// Use `user_interrupt_stack_adjust_loads_stack_pointer()` to interpret.
+	pub user_interrupt_stack_adjust: u64,
+
+	/// The user interrupt target table size, `UITTSZ` (bits 31:0), the user interrupt notification vector, `UINV` (bits 39:32), and the user interrupt flag, `UIF` (bit 63).
+	///
+	/// Use `user_interrupt_target_table_size()`, `user_interrupt_notification_vector()` and `user_interrupt_flag()` to decode.
+	pub miscellaneous: u64,
+
+	/// Value of the `IA32_UINTR_PD` MSR, `UPIDADDR`.
+	///
+	/// The linear address of the user posted-interrupt descriptor (`UPID`).
+	pub user_posted_interrupt_descriptor_address: u64,
+
+	/// Value of the `IA32_UINTR_RR` MSR, `UIRR`.
+	///
+	/// The user interrupt request register; one bit for each of the 64 user interrupt vectors.
+	pub user_interrupt_request_register: u64,
+
+	/// Value of the `IA32_UINTR_TT` MSR, `UITTADDR` and valid bit.
+	///
+	/// Use `user_interrupt_target_table_address()` and `user_interrupt_target_table_valid()` to decode.
+	pub user_interrupt_target_table: u64,
+}
+
+impl UserInterruptState
+{
+	/// If `true`, delivery of an user interrupt loads the stack pointer with `user_interrupt_stack_adjust` (ignoring bit 0); otherwise, the stack pointer is decremented by `user_interrupt_stack_adjust`.
+	#[inline(always)]
+	pub fn user_interrupt_stack_adjust_loads_stack_pointer(&self) -> bool
+	{
+		self.user_interrupt_stack_adjust & 0b1 != 0
+	}
+
+	/// User interrupt target table size, `UITTSZ`.
+	///
+	/// The highest valid index in the user interrupt target table.
+	#[inline(always)]
+	pub fn user_interrupt_target_table_size(&self) -> u32
+	{
+		self.miscellaneous as u32
+	}
+
+	/// User interrupt notification vector, `UINV`.
+	#[inline(always)]
+	pub fn user_interrupt_notification_vector(&self) -> u8
+	{
+		(self.miscellaneous >> 32) as u8
+	}
+
+	/// User interrupt flag, `UIF`.
+	///
+	/// When set (`true`), user interrupts are delivered.
+	#[inline(always)]
+	pub fn user_interrupt_flag(&self) -> bool
+	{
+		self.miscellaneous & (1 << 63) != 0
+	}
+
+	/// Linear address of the user interrupt target table, `UITTADDR`.
+	///
+	/// Always 16 byte aligned.
+	#[inline(always)]
+	pub fn user_interrupt_target_table_address(&self) -> u64
+	{
+		self.user_interrupt_target_table & !0xF
+	}
+
+	/// Is the user interrupt target table valid?
+	///
+	/// If not (`false`), `SENDUIPI` causes a general-protection fault (`#GP`).
+	#[inline(always)]
+	pub fn user_interrupt_target_table_valid(&self) -> bool
+	{
+		self.user_interrupt_target_table & 0b1 != 0
+	}
+}
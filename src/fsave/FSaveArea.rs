@@ -0,0 +1,121 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The x87 FPU state saved by `FSAVE` and `FNSAVE` and restored by `FRSTOR`.
+///
+/// Comprises a x87 FPU environment (see `FpuEnvironment`) followed by the eight x87 FPU data registers, packed as 10 bytes each, in stack order (`ST(0)` first).
+/// In memory this is 94 bytes (16-bit formats) or 108 bytes (32-bit formats); see `FpuEnvironmentFormat`.
+///
+/// This is also the format of the `NT_PRFPREG` note of 32-bit x86 core dumps (`struct user_i387_struct`), which is always `ProtectedMode32Bit`.
+///
+/// See Section 8.1.10 (Saving the x87 FPU’s State with FSTENV/FNSTENV and FSAVE/FNSAVE) of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FSaveArea
+{
+	/// x87 FPU environment.
+	pub environment: FpuEnvironment,
+
+	/// Stored values of the floating pointer registers `ST(0)` to `ST(7)` or legacy MMX registers `MM0` to `MM7`, in stack order.
+	pub registers: [ExtendedPrecisionFloatingPointValue; 8],
+}
+
+impl FSaveArea
+{
+	/// From the `x87` state of a `FXSAVE` area saved by `save_variant`.
+	///
+	/// Returns `Err(offset)` if the instruction or data pointer `offset` does not fit in 32 bits; see `FpuEnvironment::from_fxsave_area()`.
+	#[inline(always)]
+	pub fn from_fxsave_area(fxsave_area: &FXSaveArea, save_variant: X87SaveVariant) -> Result<Self, u64>
+	{
+		let x87_state_part_2 = &fxsave_area.x87_state_part_2;
+
+		let mut registers: [ExtendedPrecisionFloatingPointValue; 8] = Default::default();
+		for (i, register) in registers.iter_mut().enumerate()
+		{
+			*register = x87_state_part_2.st(i as u8).extended_precision_floating_point_value();
+		}
+
+		Ok
+		(
+			Self
+			{
+				environment: FpuEnvironment::from_fxsave_area(fxsave_area, save_variant)?,
+				registers,
+			}
+		)
+	}
+
+	/// To a `FXSAVE` area to be restored with the form of `FXRSTOR` that matches `save_variant`.
+	///
+	/// The `SSE` state is left in its initial state, with the `MXCSR` register at its default value of `0x1F80`.
+	///
+	/// See `FpuEnvironment::write_to_x87_state_part_1()` for what is lost.
+	#[inline(always)]
+	pub fn to_fxsave_area(&self, save_variant: X87SaveVariant) -> FXSaveArea
+	{
+		let mut fxsave_area = FXSaveArea::default();
+		self.write_to_fxsave_area(&mut fxsave_area, save_variant);
+		fxsave_area.sse_state_part_1.mxcsr_register_value = MxcsrRegisterValue::Initial;
+		fxsave_area
+	}
+
+	/// Size in bytes of the eight packed x87 FPU data registers.
+	pub const RegistersSize: usize = 8 * 10;
+
+	/// Reads the state saved by `FSAVE` in `format`.
+	///
+	/// `bytes` must be at least `format.fsave_area_size()` long.
+	#[inline(always)]
+	pub fn read(bytes: &[u8], format: FpuEnvironmentFormat) -> Self
+	{
+		assert!(bytes.len() >= format.fsave_area_size(), "bytes are too short for format `{:?}`", format);
+
+		let environment_size = format.environment_size();
+
+		let mut registers: [ExtendedPrecisionFloatingPointValue; 8] = Default::default();
+		for (i, register) in registers.iter_mut().enumerate()
+		{
+			*register = ExtendedPrecisionFloatingPointValue::from(array_ref!(bytes, environment_size + i * 10, 10));
+		}
+
+		Self
+		{
+			environment: FpuEnvironment::read(bytes, format),
+			registers,
+		}
+	}
+
+	/// Writes the state in `format`, as `FSAVE` would.
+	///
+	/// `bytes` must be at least `format.fsave_area_size()` long.
+	#[inline(always)]
+	pub fn write(&self, bytes: &mut [u8], format: FpuEnvironmentFormat)
+	{
+		assert!(bytes.len() >= format.fsave_area_size(), "bytes are too short for format `{:?}`", format);
+
+		self.environment.write(bytes, format);
+
+		let environment_size = format.environment_size();
+		for (i, register) in self.registers.iter().enumerate()
+		{
+			*array_mut_ref!(bytes, environment_size + i * 10, 10) = register.to_bytes();
+		}
+	}
+
+	/// Writes the `x87` state of a `FXSAVE` area to be restored with the form of `FXRSTOR` that matches `save_variant`; the `SSE` state is unchanged.
+	///
+	/// See `FpuEnvironment::write_to_x87_state_part_1()` for what is lost.
+	#[inline(always)]
+	pub fn write_to_fxsave_area(&self, fxsave_area: &mut FXSaveArea, save_variant: X87SaveVariant)
+	{
+		let (x87_state_part_1, x87_state_part_2) = fxsave_area.x87_state_mut();
+		self.environment.write_to_x87_state_part_1(x87_state_part_1, save_variant);
+		for (i, register) in self.registers.iter().enumerate()
+		{
+			let st = x87_state_part_2.st_mut(i as u8);
+			*st = FloatingPointOrMMRegisterValue::default();
+			st.set_extended_precision_floating_point_value(*register);
+		}
+	}
+}
@@ -0,0 +1,293 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// A x87 FPU environment, independent of the format it was saved in.
+///
+/// Saved by `FSTENV` and `FNSTENV` and restored by `FLDENV`; see `FpuEnvironmentFormat` for the formats.
+///
+/// Real-address mode formats save linear addresses rather than selectors and offsets.
+/// When reading these formats, the linear address is stored in the offset and the selector is zero; when writing them, the linear address is computed as `(selector << 4) + offset`.
+/// Thus reading and writing in the same format is lossless.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FpuEnvironment
+{
+	/// x87 FPU Control Word, `FCW`.
+	pub fpu_control_word: X87FloatingPointUnitControlWord,
+
+	/// x87 FPU Status Word, `FSW`.
+	pub fpu_status_word: X87FloatingPointUnitStatusWord,
+
+	/// x87 FPU Tag Word, `FTW` (unabridged).
+	///
+	/// Bits `2j + 1` and `2j` are the tag of physical register `Rj`; see `X87Tag`.
+	pub fpu_tag_word: u16,
+
+	/// x87 FPU Instruction Pointer Offset, `FIP`.
+	pub fpu_instruction_pointer_offset: u32,
+
+	/// x87 FPU Instruction Pointer Selector, `FCS`.
+	pub fpu_instruction_pointer_selector: u16,
+
+	/// x87 FPU Opcode, `FOP`.
+	pub fpu_opcode: FloatingPointUnitOpcode,
+
+	/// x87 FPU Data Pointer Offset, `FDP`.
+	pub fpu_data_pointer_offset: u32,
+
+	/// x87 FPU Data Pointer Selector, `FDS`.
+	pub fpu_data_pointer_selector: u16,
+}
+
+impl FpuEnvironment
+{
+	/// From the `x87` state of a `FXSAVE` area saved by `save_variant`.
+	///
+	/// The full tag word is reconstructed from the abridged tag word and the register values, as `FSAVE` would report it.
+	///
+	/// Returns `Err(offset)` if `save_variant` saves 64-bit offsets and the instruction or data pointer `offset` does not fit in 32 bits; selectors are not saved by such variants and are zero.
+	#[inline(always)]
+	pub fn from_fxsave_area(fxsave_area: &FXSaveArea, save_variant: X87SaveVariant) -> Result<Self, u64>
+	{
+		let (x87_state_part_1, x87_state_part_2) = fxsave_area.x87_state();
+		let mut this = Self::from_x87_state_part_1(x87_state_part_1, save_variant)?;
+		this.fpu_tag_word = X87RegisterStack::new(x87_state_part_1, x87_state_part_2).fpu_tag_word();
+		Ok(this)
+	}
+
+	/// From `x87` state saved by `save_variant`.
+	///
+	/// The abridged tag word does not record the class of non-empty registers, so they are tagged as `X87Tag::Valid`; `FLDENV` only considers whether a tag is empty.
+	/// Use `from_fxsave_area()` to reconstruct the full tag word from the register values.
+	///
+	/// Returns `Err(offset)` if `save_variant` saves 64-bit offsets and the instruction or data pointer `offset` does not fit in 32 bits; selectors are not saved by such variants and are zero.
+	#[inline(always)]
+	pub fn from_x87_state_part_1(x87_state_part_1: &X87StatePart1, save_variant: X87SaveVariant) -> Result<Self, u64>
+	{
+		#[inline(always)]
+		fn offset_to_32_bit(offset: u64) -> Result<u32, u64>
+		{
+			u32::try_from(offset).map_err(|_| offset)
+		}
+
+		let (fpu_instruction_pointer_offset, fpu_instruction_pointer_selector, fpu_data_pointer_offset, fpu_data_pointer_selector) = if save_variant.saves_64_bit_offsets()
+		{
+			let instruction_pointer = unsafe { x87_state_part_1.fpu_instruction_pointer_offset.mode_64_bit_with_rex_w };
+			let data_pointer = unsafe { x87_state_part_1.fpu_data_pointer_offset.mode_64_bit_with_rex_w };
+			(offset_to_32_bit(instruction_pointer.instruction_pointer_offset)?, 0, offset_to_32_bit(data_pointer.data_pointer_offset)?, 0)
+		}
+		else
+		{
+			let instruction_pointer = unsafe { x87_state_part_1.fpu_instruction_pointer_offset.mode_32_bit };
+			let data_pointer = unsafe { x87_state_part_1.fpu_data_pointer_offset.mode_32_bit };
+			(instruction_pointer.instruction_pointer_offset, instruction_pointer.instruction_pointer_selector, data_pointer.data_pointer_offset, data_pointer.data_pointer_selector)
+		};
+
+		let fpu_tag_word = (0 .. 8).fold(0, |fpu_tag_word, j|
+		{
+			let tag = if x87_state_part_1.abridged_fpu_tag_word.fpu_data_register_STj_has_an_empty_tag(j)
+			{
+				X87Tag::Empty
+			}
+			else
+			{
+				X87Tag::Valid
+			};
+			fpu_tag_word | ((tag as u16) << (j * 2))
+		});
+
+		Ok
+		(
+			Self
+			{
+				fpu_control_word: x87_state_part_1.fpu_control_word,
+				fpu_status_word: x87_state_part_1.fpu_status_word,
+				fpu_tag_word,
+				fpu_instruction_pointer_offset,
+				fpu_instruction_pointer_selector,
+				fpu_opcode: x87_state_part_1.fpu_opcode,
+				fpu_data_pointer_offset,
+				fpu_data_pointer_selector,
+			}
+		)
+	}
+
+	/// Reads a x87 FPU environment saved in `format`.
+	///
+	/// `bytes` must be at least `format.environment_size()` long.
+	#[inline(always)]
+	pub fn read(bytes: &[u8], format: FpuEnvironmentFormat) -> Self
+	{
+		use self::FpuEnvironmentFormat::*;
+
+		assert!(bytes.len() >= format.environment_size(), "bytes are too short for format `{:?}`", format);
+
+		let word = |index: usize| u16::from_le_bytes(*array_ref!(bytes, index * 2, 2));
+		let double_word = |index: usize| u32::from_le_bytes(*array_ref!(bytes, index * 4, 4));
+
+		match format
+		{
+			RealMode16Bit => Self
+			{
+				fpu_control_word: X87FloatingPointUnitControlWord::new(word(0)),
+				fpu_status_word: X87FloatingPointUnitStatusWord::new(word(1)),
+				fpu_tag_word: word(2),
+				fpu_instruction_pointer_offset: ((word(4) as u32 & 0xF000) << 4) | (word(3) as u32),
+				fpu_instruction_pointer_selector: 0,
+				fpu_opcode: FloatingPointUnitOpcode::new(word(4) & FloatingPointUnitOpcode::Mask),
+				fpu_data_pointer_offset: ((word(6) as u32 & 0xF000) << 4) | (word(5) as u32),
+				fpu_data_pointer_selector: 0,
+			},
+
+			ProtectedMode16Bit => Self
+			{
+				fpu_control_word: X87FloatingPointUnitControlWord::new(word(0)),
+				fpu_status_word: X87FloatingPointUnitStatusWord::new(word(1)),
+				fpu_tag_word: word(2),
+				fpu_instruction_pointer_offset: word(3) as u32,
+				fpu_instruction_pointer_selector: word(4),
+				fpu_opcode: FloatingPointUnitOpcode::default(),
+				fpu_data_pointer_offset: word(5) as u32,
+				fpu_data_pointer_selector: word(6),
+			},
+
+			RealMode32Bit => Self
+			{
+				fpu_control_word: X87FloatingPointUnitControlWord::new(double_word(0) as u16),
+				fpu_status_word: X87FloatingPointUnitStatusWord::new(double_word(1) as u16),
+				fpu_tag_word: double_word(2) as u16,
+				fpu_instruction_pointer_offset: ((double_word(4) & 0x0FFF_F000) << 4) | (double_word(3) & 0xFFFF),
+				fpu_instruction_pointer_selector: 0,
+				fpu_opcode: FloatingPointUnitOpcode::new((double_word(4) as u16) & FloatingPointUnitOpcode::Mask),
+				fpu_data_pointer_offset: ((double_word(6) & 0x0FFF_F000) << 4) | (double_word(5) & 0xFFFF),
+				fpu_data_pointer_selector: 0,
+			},
+
+			ProtectedMode32Bit => Self
+			{
+				fpu_control_word: X87FloatingPointUnitControlWord::new(double_word(0) as u16),
+				fpu_status_word: X87FloatingPointUnitStatusWord::new(double_word(1) as u16),
+				fpu_tag_word: double_word(2) as u16,
+				fpu_instruction_pointer_offset: double_word(3),
+				fpu_instruction_pointer_selector: double_word(4) as u16,
+				fpu_opcode: FloatingPointUnitOpcode::new(((double_word(4) >> 16) as u16) & FloatingPointUnitOpcode::Mask),
+				fpu_data_pointer_offset: double_word(5),
+				fpu_data_pointer_selector: double_word(6) as u16,
+			},
+		}
+	}
+
+	/// Writes this x87 FPU environment in `format`, as `FSTENV` would; reserved bits are written as zero.
+	///
+	/// The opcode is lost in the `ProtectedMode16Bit` format; in the `RealMode16Bit` format, linear addresses are truncated to 20 bits.
+	///
+	/// `bytes` must be at least `format.environment_size()` long.
+	#[inline(always)]
+	pub fn write(&self, bytes: &mut [u8], format: FpuEnvironmentFormat)
+	{
+		use self::FpuEnvironmentFormat::*;
+
+		assert!(bytes.len() >= format.environment_size(), "bytes are too short for format `{:?}`", format);
+
+		let mut words: [u16; 7] = [0; 7];
+		let mut double_words: [u32; 7] = [0; 7];
+
+		let instruction_pointer_linear_address = ((self.fpu_instruction_pointer_selector as u32) << 4).wrapping_add(self.fpu_instruction_pointer_offset);
+		let data_pointer_linear_address = ((self.fpu_data_pointer_selector as u32) << 4).wrapping_add(self.fpu_data_pointer_offset);
+		let fpu_opcode = self.fpu_opcode.value() & FloatingPointUnitOpcode::Mask;
+
+		match format
+		{
+			RealMode16Bit =>
+			{
+				words[3] = instruction_pointer_linear_address as u16;
+				words[4] = (((instruction_pointer_linear_address >> 4) & 0xF000) as u16) | fpu_opcode;
+				words[5] = data_pointer_linear_address as u16;
+				words[6] = ((data_pointer_linear_address >> 4) & 0xF000) as u16;
+			}
+
+			ProtectedMode16Bit =>
+			{
+				words[3] = self.fpu_instruction_pointer_offset as u16;
+				words[4] = self.fpu_instruction_pointer_selector;
+				words[5] = self.fpu_data_pointer_offset as u16;
+				words[6] = self.fpu_data_pointer_selector;
+			}
+
+			RealMode32Bit =>
+			{
+				double_words[3] = instruction_pointer_linear_address & 0xFFFF;
+				double_words[4] = ((instruction_pointer_linear_address >> 4) & 0x0FFF_F000) | (fpu_opcode as u32);
+				double_words[5] = data_pointer_linear_address & 0xFFFF;
+				double_words[6] = (data_pointer_linear_address >> 4) & 0x0FFF_F000;
+			}
+
+			ProtectedMode32Bit =>
+			{
+				double_words[3] = self.fpu_instruction_pointer_offset;
+				double_words[4] = ((fpu_opcode as u32) << 16) | (self.fpu_instruction_pointer_selector as u32);
+				double_words[5] = self.fpu_data_pointer_offset;
+				double_words[6] = self.fpu_data_pointer_selector as u32;
+			}
+		}
+
+		if format.is_32_bit()
+		{
+			double_words[0] = self.fpu_control_word.value() as u32;
+			double_words[1] = self.fpu_status_word.value() as u32;
+			double_words[2] = self.fpu_tag_word as u32;
+			for (index, double_word) in double_words.iter().enumerate()
+			{
+				*array_mut_ref!(bytes, index * 4, 4) = double_word.to_le_bytes();
+			}
+		}
+		else
+		{
+			words[0] = self.fpu_control_word.value();
+			words[1] = self.fpu_status_word.value();
+			words[2] = self.fpu_tag_word;
+			for (index, word) in words.iter().enumerate()
+			{
+				*array_mut_ref!(bytes, index * 2, 2) = word.to_le_bytes();
+			}
+		}
+	}
+
+	/// Writes the x87 FPU environment into the `x87` state of a `FXSAVE` area, using the forms of the instruction and data pointers that `save_variant` saves.
+	///
+	/// This is lossy:-
+	///
+	/// * The full tag word is abridged, so only whether each physical register is empty is retained; `FXRSTOR` recomputes the other tags from the register values.
+	/// * If `save_variant` saves 64-bit offsets, the selectors are not written.
+	#[inline(always)]
+	pub fn write_to_x87_state_part_1(&self, x87_state_part_1: &mut X87StatePart1, save_variant: X87SaveVariant)
+	{
+		let mut register_stack = X87RegisterStack::default();
+		register_stack.set_fpu_tag_word(self.fpu_tag_word);
+
+		x87_state_part_1.fpu_control_word = self.fpu_control_word;
+		x87_state_part_1.fpu_status_word = self.fpu_status_word;
+		x87_state_part_1.abridged_fpu_tag_word = register_stack.abridged_fpu_tag_word();
+		x87_state_part_1.fpu_opcode = self.fpu_opcode;
+
+		let mut fpu_instruction_pointer_offset = FloatingPointUnitInstructionPointerOffset::default();
+		let mut fpu_data_pointer_offset = FloatingPointUnitDataPointerOffset::default();
+		unsafe
+		{
+			if save_variant.saves_64_bit_offsets()
+			{
+				fpu_instruction_pointer_offset.mode_64_bit_with_rex_w.instruction_pointer_offset = self.fpu_instruction_pointer_offset as u64;
+				fpu_data_pointer_offset.mode_64_bit_with_rex_w.data_pointer_offset = self.fpu_data_pointer_offset as u64;
+			}
+			else
+			{
+				fpu_instruction_pointer_offset.mode_32_bit.instruction_pointer_offset = self.fpu_instruction_pointer_offset;
+				fpu_instruction_pointer_offset.mode_32_bit.instruction_pointer_selector = self.fpu_instruction_pointer_selector;
+				fpu_data_pointer_offset.mode_32_bit.data_pointer_offset = self.fpu_data_pointer_offset;
+				fpu_data_pointer_offset.mode_32_bit.data_pointer_selector = self.fpu_data_pointer_selector;
+			}
+		}
+		x87_state_part_1.fpu_instruction_pointer_offset = fpu_instruction_pointer_offset;
+		x87_state_part_1.fpu_data_pointer_offset = fpu_data_pointer_offset;
+	}
+}
@@ -0,0 +1,80 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The format of a x87 FPU environment, as saved by `FSTENV` and `FNSTENV` (and as the first part of the state saved by `FSAVE` and `FNSAVE`).
+///
+/// The format depends on the operating mode and the operand-size attribute of the instruction.
+///
+/// See Figures 8-9 to 8-12 of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum FpuEnvironmentFormat
+{
+	/// Real-address or virtual-8086 mode, 16-bit operand size (14 bytes).
+	///
+	/// Instruction and data pointers are 20-bit linear addresses.
+	RealMode16Bit,
+
+	/// Protected mode, 16-bit operand size (14 bytes).
+	///
+	/// There is no opcode.
+	ProtectedMode16Bit,
+
+	/// Real-address or virtual-8086 mode, 32-bit operand size (28 bytes).
+	///
+	/// Instruction and data pointers are 32-bit linear addresses.
+	RealMode32Bit,
+
+	/// Protected mode, 32-bit operand size (28 bytes).
+	ProtectedMode32Bit,
+}
+
+impl FpuEnvironmentFormat
+{
+	/// Size in bytes of a x87 FPU environment.
+	#[inline(always)]
+	pub fn environment_size(self) -> usize
+	{
+		if self.is_32_bit()
+		{
+			28
+		}
+		else
+		{
+			14
+		}
+	}
+
+	/// Size in bytes of the state saved by `FSAVE` or `FNSAVE`: the x87 FPU environment followed by the eight 10-byte x87 FPU data registers.
+	#[inline(always)]
+	pub fn fsave_area_size(self) -> usize
+	{
+		self.environment_size() + FSaveArea::RegistersSize
+	}
+
+	/// Is this a 32-bit operand size format?
+	#[inline(always)]
+	pub fn is_32_bit(self) -> bool
+	{
+		use self::FpuEnvironmentFormat::*;
+
+		match self
+		{
+			RealMode32Bit | ProtectedMode32Bit => true,
+			RealMode16Bit | ProtectedMode16Bit => false,
+		}
+	}
+
+	/// Is this a real-address or virtual-8086 mode format?
+	#[inline(always)]
+	pub fn is_real_mode(self) -> bool
+	{
+		use self::FpuEnvironmentFormat::*;
+
+		match self
+		{
+			RealMode16Bit | RealMode32Bit => true,
+			ProtectedMode16Bit | ProtectedMode32Bit => false,
+		}
+	}
+}
@@ -0,0 +1,476 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Live operations on the current thread's x87 FPU state.
+///
+/// The 'wait' forms (`FCLEX`, `FINIT`, `FSTENV`) first raise any pending unmasked floating point exceptions; the 'no-wait' forms (`FNCLEX`, `FNINIT`, `FNSTENV`) do not.
+///
+/// See Section 8.3.12 (Waiting vs. Non-waiting Instructions) and Section 8.1.10 (Saving the x87 FPU’s State with FSTENV/FNSTENV and FSAVE/FNSAVE) of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[derive(Debug)]
+pub struct X87FloatingPointUnit;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl X87FloatingPointUnit
+{
+	/// The format used by `FSTENV`, `FNSTENV` and `FLDENV` in 32-bit protected mode and in 64-bit mode.
+	const EnvironmentFormat: FpuEnvironmentFormat = FpuEnvironmentFormat::ProtectedMode32Bit;
+
+	/// Loads the control word.
+	///
+	/// Uses the `FLDCW` instruction; see <https://github.com/HJLebbink/asm-dude/wiki/FLDCW>.
+	#[inline(always)]
+	pub fn load_control_word(fpu_control_word: X87FloatingPointUnitControlWord)
+	{
+		fpu_control_word.restore()
+	}
+
+	/// Waits for any pending unmasked floating point exceptions to be raised.
+	///
+	/// Uses the `FWAIT` instruction; see <https://github.com/HJLebbink/asm-dude/wiki/WAIT_FWAIT>.
+	#[inline(always)]
+	pub fn wait()
+	{
+		unsafe
+		{
+			asm!
+			(
+				"fwait"
+				:
+					// Output constraints.
+				:
+					// Input constraints.
+				:
+					// Clobbers.
+				:
+					// Options.
+					"volatile"
+			);
+		}
+	}
+
+	/// Clears the exception flags, the exception summary status flag, the stack fault flag and the busy flag in the status word, after raising any pending unmasked floating point exceptions.
+	///
+	/// Uses the `FCLEX` instruction; see <https://github.com/HJLebbink/asm-dude/wiki/FCLEX_FNCLEX>.
+	#[inline(always)]
+	pub fn clear_exceptions_after_raising_any_pending_unmasked_floating_point_exceptions()
+	{
+		unsafe
+		{
+			asm!
+			(
+				"fclex"
+				:
+					// Output constraints.
+				:
+					// Input constraints.
+				:
+					// Clobbers.
+				:
+					// Options.
+					"volatile"
+			);
+		}
+	}
+
+	/// Clears the exception flags, the exception summary status flag, the stack fault flag and the busy flag in the status word.
+	///
+	/// Uses the `FNCLEX` instruction; see <https://github.com/HJLebbink/asm-dude/wiki/FCLEX_FNCLEX>.
+	#[inline(always)]
+	pub fn clear_exceptions()
+	{
+		unsafe
+		{
+			asm!
+			(
+				"fnclex"
+				:
+					// Output constraints.
+				:
+					// Input constraints.
+				:
+					// Clobbers.
+				:
+					// Options.
+					"volatile"
+			);
+		}
+	}
+
+	/// Initializes the x87 FPU to its initial state (see `X87StatePart1::initial()`), after raising any pending unmasked floating point exceptions.
+	///
+	/// All data registers are marked empty; their contents are unchanged.
+	///
+	/// Uses the `FINIT` instruction; see <https://github.com/HJLebbink/asm-dude/wiki/FINIT_FNINIT>.
+	#[inline(always)]
+	pub fn initialize_after_raising_any_pending_unmasked_floating_point_exceptions()
+	{
+		unsafe
+		{
+			asm!
+			(
+				"finit"
+				:
+					// Output constraints.
+				:
+					// Input constraints.
+				:
+					// Clobbers.
+				:
+					// Options.
+					"volatile"
+			);
+		}
+	}
+
+	/// Initializes the x87 FPU to its initial state (see `X87StatePart1::initial()`).
+	///
+	/// All data registers are marked empty; their contents are unchanged.
+	///
+	/// Uses the `FNINIT` instruction; see <https://github.com/HJLebbink/asm-dude/wiki/FINIT_FNINIT>.
+	#[inline(always)]
+	pub fn initialize()
+	{
+		unsafe
+		{
+			asm!
+			(
+				"fninit"
+				:
+					// Output constraints.
+				:
+					// Input constraints.
+				:
+					// Clobbers.
+				:
+					// Options.
+					"volatile"
+			);
+		}
+	}
+
+	/// Stores the x87 FPU environment, after raising any pending unmasked floating point exceptions.
+	///
+	/// `FSTENV` masks all floating point exceptions once it has stored the environment; this function then reloads the stored control word, so the x87 FPU state is unchanged.
+	///
+	/// Uses the `FSTENV` and `FLDCW` instructions; see <https://github.com/HJLebbink/asm-dude/wiki/FSTENV_FNSTENV>.
+	#[inline(always)]
+	pub fn store_environment_after_raising_any_pending_unmasked_floating_point_exceptions() -> FpuEnvironment
+	{
+		let mut environment: [u8; 28] = [0; 28];
+		let environment_pointer = &mut environment;
+
+		unsafe
+		{
+			asm!
+			(
+				"fstenv $0\n\tfldcw $0"
+				:
+					// Output constraints.
+					"+*m"(environment_pointer)
+				:
+					// Input constraints.
+				:
+					// Clobbers.
+				:
+					// Options.
+					"volatile"
+			);
+		}
+
+		FpuEnvironment::read(&environment[..], Self::EnvironmentFormat)
+	}
+
+	/// Stores the x87 FPU environment.
+	///
+	/// `FNSTENV` masks all floating point exceptions once it has stored the environment; this function then reloads the stored control word, so the x87 FPU state is unchanged.
+	///
+	/// Uses the `FNSTENV` and `FLDCW` instructions; see <https://github.com/HJLebbink/asm-dude/wiki/FSTENV_FNSTENV>.
+	#[inline(always)]
+	pub fn store_environment() -> FpuEnvironment
+	{
+		let mut environment: [u8; 28] = [0; 28];
+		let environment_pointer = &mut environment;
+
+		unsafe
+		{
+			asm!
+			(
+				"fnstenv $0\n\tfldcw $0"
+				:
+					// Output constraints.
+					"+*m"(environment_pointer)
+				:
+					// Input constraints.
+				:
+					// Clobbers.
+				:
+					// Options.
+					"volatile"
+			);
+		}
+
+		FpuEnvironment::read(&environment[..], Self::EnvironmentFormat)
+	}
+
+	/// Loads the x87 FPU environment.
+	///
+	/// Only whether each tag is empty is significant.
+	/// If the loaded status word has an unmasked exception flag set, the exception is raised by the next 'wait' x87 FPU instruction.
+	///
+	/// Uses the `FLDENV` instruction; see <https://github.com/HJLebbink/asm-dude/wiki/FLDENV>.
+	#[inline(always)]
+	pub fn load_environment(fpu_environment: &FpuEnvironment)
+	{
+		let mut environment: [u8; 28] = [0; 28];
+		fpu_environment.write(&mut environment[..], Self::EnvironmentFormat);
+		let environment_pointer = &environment;
+
+		unsafe
+		{
+			asm!
+			(
+				"fldenv $0"
+				:
+					// Output constraints.
+				:
+					// Input constraints.
+					"*m"(environment_pointer)
+				:
+					// Clobbers.
+				:
+					// Options.
+					"volatile"
+			);
+		}
+	}
+
+	/// Stores the x87 FPU environment into `x87` state, as `save_variant` would, without changing the x87 FPU state.
+	///
+	/// Uses the `FNSTENV` instruction; see `store_environment()` and `FpuEnvironment::write_to_x87_state_part_1()`.
+	#[inline(always)]
+	pub fn store_environment_into_x87_state_part_1(x87_state_part_1: &mut X87StatePart1, save_variant: X87SaveVariant)
+	{
+		Self::store_environment().write_to_x87_state_part_1(x87_state_part_1, save_variant)
+	}
+
+	/// Loads the x87 FPU environment from `x87` state saved by `save_variant`.
+	///
+	/// Uses the `FLDENV` instruction; see `load_environment()`.
+	///
+	/// Returns `Err(offset)`, without loading anything, if the instruction or data pointer `offset` does not fit in 32 bits; see `FpuEnvironment::from_x87_state_part_1()`.
+	#[inline(always)]
+	pub fn load_environment_from_x87_state_part_1(x87_state_part_1: &X87StatePart1, save_variant: X87SaveVariant) -> Result<(), u64>
+	{
+		Self::load_environment(&FpuEnvironment::from_x87_state_part_1(x87_state_part_1, save_variant)?);
+		Ok(())
+	}
+}
+
+#[cfg(all(test, any(target_arch = "x86", target_arch = "x86_64")))]
+mod X87FloatingPointUnitTests
+{
+	use super::*;
+
+	const AllEmpty: u16 = 0xFFFF;
+
+	#[test]
+	fn load_control_word_is_read_back()
+	{
+		X87FloatingPointUnit::initialize();
+
+		let control_word = X87FloatingPointUnitControlWord::new(0x0E7F);
+		X87FloatingPointUnit::load_control_word(control_word);
+		let loaded = X87FloatingPointUnitControlWord::save();
+
+		X87FloatingPointUnit::initialize();
+		assert_eq!(loaded, control_word);
+	}
+
+	#[test]
+	fn wait_without_pending_exceptions_leaves_state_unchanged()
+	{
+		X87FloatingPointUnit::initialize();
+
+		let control_word = X87FloatingPointUnitControlWord::new(0x0E7F);
+		X87FloatingPointUnit::load_control_word(control_word);
+		X87FloatingPointUnit::wait();
+		let after_control_word = X87FloatingPointUnitControlWord::save();
+		let after_status_word = X87FloatingPointUnitStatusWord::save();
+
+		X87FloatingPointUnit::initialize();
+		assert_eq!(after_control_word, control_word);
+		assert_eq!(after_status_word.value(), 0x0000);
+	}
+
+	#[test]
+	fn clear_exceptions_clears_exception_flags()
+	{
+		X87FloatingPointUnit::initialize();
+
+		// All exceptions are masked, so setting the flags does not raise an exception (nor set the exception summary status and busy flags).
+		let mut fpu_environment = X87FloatingPointUnit::store_environment();
+		fpu_environment.fpu_status_word = X87FloatingPointUnitStatusWord::new(0x007F);
+		X87FloatingPointUnit::load_environment(&fpu_environment);
+		let before = X87FloatingPointUnitStatusWord::save();
+
+		X87FloatingPointUnit::clear_exceptions();
+		let after = X87FloatingPointUnitStatusWord::save();
+
+		X87FloatingPointUnit::initialize();
+		assert_eq!(before.value(), 0x007F);
+		assert_eq!(after.value(), 0x0000);
+	}
+
+	#[test]
+	fn clear_exceptions_after_raising_any_pending_unmasked_floating_point_exceptions_clears_masked_exception_flags()
+	{
+		X87FloatingPointUnit::initialize();
+
+		// All exceptions are masked, so none is pending and `FWAIT` does not raise one.
+		let mut fpu_environment = X87FloatingPointUnit::store_environment();
+		fpu_environment.fpu_status_word = X87FloatingPointUnitStatusWord::new(0x007F);
+		X87FloatingPointUnit::load_environment(&fpu_environment);
+		let before = X87FloatingPointUnitStatusWord::save();
+
+		X87FloatingPointUnit::clear_exceptions_after_raising_any_pending_unmasked_floating_point_exceptions();
+		let after = X87FloatingPointUnitStatusWord::save();
+
+		X87FloatingPointUnit::initialize();
+		assert_eq!(before.value(), 0x007F);
+		assert_eq!(after.value(), 0x0000);
+	}
+
+	#[test]
+	fn initialize_resets_control_status_and_tag_words()
+	{
+		X87FloatingPointUnit::load_control_word(X87FloatingPointUnitControlWord::new(0x0E7F));
+
+		X87FloatingPointUnit::initialize();
+		let control_word = X87FloatingPointUnitControlWord::save();
+		let status_word = X87FloatingPointUnitStatusWord::save();
+		let fpu_environment = X87FloatingPointUnit::store_environment();
+
+		assert_eq!(control_word, X87FloatingPointUnitControlWord::Initial);
+		assert_eq!(status_word.value(), 0x0000);
+		assert_eq!(fpu_environment.fpu_tag_word, AllEmpty);
+	}
+
+	#[test]
+	fn initialize_after_raising_any_pending_unmasked_floating_point_exceptions_resets_control_status_and_tag_words()
+	{
+		X87FloatingPointUnit::load_control_word(X87FloatingPointUnitControlWord::new(0x0E7F));
+
+		X87FloatingPointUnit::initialize_after_raising_any_pending_unmasked_floating_point_exceptions();
+		let control_word = X87FloatingPointUnitControlWord::save();
+		let status_word = X87FloatingPointUnitStatusWord::save();
+		let fpu_environment = X87FloatingPointUnit::store_environment();
+
+		X87FloatingPointUnit::initialize();
+		assert_eq!(control_word, X87FloatingPointUnitControlWord::Initial);
+		assert_eq!(status_word.value(), 0x0000);
+		assert_eq!(fpu_environment.fpu_tag_word, AllEmpty);
+	}
+
+	#[test]
+	fn store_environment_leaves_control_word_unchanged()
+	{
+		X87FloatingPointUnit::initialize();
+
+		// Unmasks the zero divide exception, which `FNSTENV` masks.
+		let control_word = X87FloatingPointUnitControlWord::new(0x037B);
+		X87FloatingPointUnit::load_control_word(control_word);
+		let fpu_environment = X87FloatingPointUnit::store_environment();
+		let after = X87FloatingPointUnitControlWord::save();
+
+		X87FloatingPointUnit::initialize();
+		assert_eq!(fpu_environment.fpu_control_word, control_word);
+		assert_eq!(fpu_environment.fpu_status_word.value(), 0x0000);
+		assert_eq!(fpu_environment.fpu_tag_word, AllEmpty);
+		assert_eq!(after, control_word);
+	}
+
+	#[test]
+	fn store_environment_after_raising_any_pending_unmasked_floating_point_exceptions_leaves_control_word_unchanged()
+	{
+		X87FloatingPointUnit::initialize();
+
+		// Unmasks the zero divide exception, which `FNSTENV` masks; no exception is pending, so `FWAIT` does not raise one.
+		let control_word = X87FloatingPointUnitControlWord::new(0x037B);
+		X87FloatingPointUnit::load_control_word(control_word);
+		let fpu_environment = X87FloatingPointUnit::store_environment_after_raising_any_pending_unmasked_floating_point_exceptions();
+		let after = X87FloatingPointUnitControlWord::save();
+
+		X87FloatingPointUnit::initialize();
+		assert_eq!(fpu_environment.fpu_control_word, control_word);
+		assert_eq!(fpu_environment.fpu_status_word.value(), 0x0000);
+		assert_eq!(fpu_environment.fpu_tag_word, AllEmpty);
+		assert_eq!(after, control_word);
+	}
+
+	#[test]
+	fn store_environment_into_x87_state_part_1_abridges_environment()
+	{
+		X87FloatingPointUnit::initialize();
+
+		let control_word = X87FloatingPointUnitControlWord::new(0x037B);
+		X87FloatingPointUnit::load_control_word(control_word);
+		let mut x87_state_part_1 = X87StatePart1::default();
+		x87_state_part_1.abridged_fpu_tag_word = AbridgedFpuTagWord::new(0xFF);
+		X87FloatingPointUnit::store_environment_into_x87_state_part_1(&mut x87_state_part_1, X87SaveVariant::FXSAVE);
+		let after = X87FloatingPointUnitControlWord::save();
+
+		X87FloatingPointUnit::initialize();
+		assert_eq!(x87_state_part_1.fpu_control_word, control_word);
+		assert_eq!(x87_state_part_1.fpu_status_word.value(), 0x0000);
+		assert_eq!(x87_state_part_1.abridged_fpu_tag_word.value(), 0x00);
+		assert_eq!(after, control_word);
+	}
+
+	#[test]
+	fn load_environment_from_x87_state_part_1_round_trips()
+	{
+		X87FloatingPointUnit::initialize();
+
+		let mut x87_state_part_1 = X87StatePart1::initial();
+		x87_state_part_1.fpu_control_word = X87FloatingPointUnitControlWord::new(0x0E7F);
+		x87_state_part_1.fpu_status_word.set_top(5);
+		x87_state_part_1.fpu_status_word.set_condition_code_c3(true);
+		// Only physical register `R0` is not empty.
+		x87_state_part_1.abridged_fpu_tag_word = AbridgedFpuTagWord::new(0b0000_0001);
+		let loaded = X87FloatingPointUnit::load_environment_from_x87_state_part_1(&x87_state_part_1, X87SaveVariant::FXSAVE);
+		let fpu_environment = X87FloatingPointUnit::store_environment();
+		let mut round_tripped = X87StatePart1::default();
+		X87FloatingPointUnit::store_environment_into_x87_state_part_1(&mut round_tripped, X87SaveVariant::FXSAVE);
+
+		X87FloatingPointUnit::initialize();
+		assert_eq!(loaded, Ok(()));
+		assert_eq!(fpu_environment.fpu_control_word, x87_state_part_1.fpu_control_word);
+		assert_eq!(fpu_environment.fpu_status_word, x87_state_part_1.fpu_status_word);
+		// The processor reclassifies a non-empty register from its contents when storing the full tag word, so only whether it is empty is checked.
+		assert_ne!(fpu_environment.fpu_tag_word & 0b11, 0b11);
+		assert_eq!(fpu_environment.fpu_tag_word >> 2, AllEmpty >> 2);
+		assert_eq!(round_tripped.fpu_control_word, x87_state_part_1.fpu_control_word);
+		assert_eq!(round_tripped.fpu_status_word, x87_state_part_1.fpu_status_word);
+		assert_eq!(round_tripped.abridged_fpu_tag_word, x87_state_part_1.abridged_fpu_tag_word);
+	}
+
+	#[test]
+	fn load_environment_is_read_back()
+	{
+		X87FloatingPointUnit::initialize();
+
+		let mut fpu_environment = X87FloatingPointUnit::store_environment();
+		fpu_environment.fpu_control_word = X87FloatingPointUnitControlWord::new(0x0E7F);
+		fpu_environment.fpu_status_word.set_top(5);
+		fpu_environment.fpu_status_word.set_condition_code_c3(true);
+		X87FloatingPointUnit::load_environment(&fpu_environment);
+		let loaded = X87FloatingPointUnit::store_environment();
+
+		X87FloatingPointUnit::initialize();
+		assert_eq!(loaded.fpu_control_word, fpu_environment.fpu_control_word);
+		assert_eq!(loaded.fpu_status_word, fpu_environment.fpu_status_word);
+		assert_eq!(loaded.fpu_tag_word, AllEmpty);
+	}
+}
@@ -9,8 +9,7 @@
 ///
 /// Documented in Table 10-2 of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1 and repeated in the documentation of the `XSAVE` area in Section 13.4.
 ///
-/// The legacy instructions `FSAVE` and `FNSAVE` (save FPU state) and `FRSTOR` (restore FPU state) also work with with this layout.
-/// The legacy instructions `FSTENV` and `FNSTENV` (save FPU environment) and `FLDENV` (restore FPU environment) also work with the layout in `x87_state_part_1`.
+/// The legacy instructions `FSAVE` and `FNSAVE` (save FPU state) and `FRSTOR` (restore FPU state), and `FSTENV` and `FNSTENV` (save FPU environment) and `FLDENV` (restore FPU environment), do *not* use this layout; see `FSaveArea` and `FpuEnvironment`, which can be converted to and from this layout.
 #[derive(Default, Debug, Clone)]
 #[repr(C, align(16))]
 pub struct FXSaveArea
@@ -45,28 +44,51 @@ pub struct FXSaveArea
 
 impl FXSaveArea
 {
-	/// Saves this `FXSAVE` area.
+	/// The variant of instruction used by `save()`.
+	#[cfg(target_arch = "x86")] pub const NativeSaveVariant: X87SaveVariant = X87SaveVariant::FXSAVE;
+
+	/// The variant of instruction used by `save()`.
+	#[cfg(target_arch = "x86_64")] pub const NativeSaveVariant: X87SaveVariant = X87SaveVariant::FXSAVE64;
+
+	/// Saves this `FXSAVE` area, returning it with the variant of instruction used, `NativeSaveVariant`.
 	///
 	/// Used to save and restore the state of the FPU (x87) and MMX (SSE).
 	///
 	/// Unlike the legacy instructions `FSAVE` and `FNSAVE`, this does not change the state of the FPU (x87) and MMX (SSE) units, registers and associated data.
+	///
+	/// On x86_64, uses `FXSAVE64`, which saves 64-bit `x87` instruction and data pointers; see `save_32_bit_format()` for an alternative.
+	/// Pass the returned variant to `restore_for_save_variant()`, `fpu_pointers()` and `compatibility_mode_state()`.
 	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "fxsr"))]
 	#[inline(always)]
-	pub fn save() -> Self
+	pub fn save() -> (Self, X87SaveVariant)
 	{
-		let mut this = unsafe { uninitialized() };
+		let mut this: Self = unsafe { uninitialized() };
 		let pointer = &mut this as *mut Self as *mut u8;
 		unsafe
 		{
 			#[cfg(target_arch = "x86")] _fxsave(pointer);
 			#[cfg(target_arch = "x86_64")] _fxsave64(pointer);
 		};
-		this
+		(this, Self::NativeSaveVariant)
+	}
+
+	/// Saves this `FXSAVE` area using the 32-bit format of `FXSAVE` (ie without `REX.W`), even on x86_64, returning it with the variant of instruction used, `X87SaveVariant::FXSAVE`.
+	///
+	/// The `x87` instruction and data pointers are saved as 32-bit offsets with 16-bit selectors, as they are for 32-bit processes.
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "fxsr"))]
+	#[inline(always)]
+	pub fn save_32_bit_format() -> (Self, X87SaveVariant)
+	{
+		let mut this: Self = unsafe { uninitialized() };
+		let pointer = &mut this as *mut Self as *mut u8;
+		unsafe { _fxsave(pointer) };
+		(this, X87SaveVariant::FXSAVE)
 	}
 
 	/// Restores this `FXSAVE` area.
 	///
 	/// If any reserved bits are set in `sse_state().0.mxcsr_register_value`, then a restore of this value will cause a general-protection fault (`#GP`).
+	/// Use `validate_mxcsr_register_value()` first to check for this.
 	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "fxsr"))]
 	#[inline(always)]
 	pub fn restore(&self)
@@ -79,6 +101,67 @@ impl FXSaveArea
 		}
 	}
 
+	/// Restores this `FXSAVE` area using the 32-bit format of `FXRSTOR` (ie without `REX.W`), even on x86_64.
+	///
+	/// Use this for areas saved with `save_32_bit_format()`.
+	///
+	/// If any reserved bits are set in `sse_state().0.mxcsr_register_value`, then a restore of this value will cause a general-protection fault (`#GP`).
+	/// Use `validate_mxcsr_register_value()` first to check for this.
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "fxsr"))]
+	#[inline(always)]
+	pub fn restore_32_bit_format(&self)
+	{
+		let pointer = self as *const Self as *const u8;
+		unsafe { _fxrstor(pointer) }
+	}
+
+	/// Restores this `FXSAVE` area using the form of `FXRSTOR` that matches `save_variant`, the variant of instruction that saved this area (as returned by `save()` or `save_32_bit_format()`).
+	///
+	/// `save_variant` must be `X87SaveVariant::FXSAVE` or `X87SaveVariant::FXSAVE64`; the latter only on x86_64.
+	///
+	/// If any reserved bits are set in `sse_state().0.mxcsr_register_value`, then a restore of this value will cause a general-protection fault (`#GP`).
+	/// Use `validate_mxcsr_register_value()` first to check for this.
+	#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "fxsr"))]
+	#[inline(always)]
+	pub fn restore_for_save_variant(&self, save_variant: X87SaveVariant)
+	{
+		match save_variant
+		{
+			X87SaveVariant::FXSAVE => self.restore_32_bit_format(),
+
+			#[cfg(target_arch = "x86_64")] X87SaveVariant::FXSAVE64 => self.restore(),
+
+			_ => panic!("save_variant `{:?}` is not a FXSAVE variant of this architecture", save_variant),
+		}
+	}
+
+	/// The `x87` instruction and data pointers, interpreted for `save_variant`, the variant of instruction that saved this area (see `X87StatePart1::fpu_pointers()`).
+	///
+	/// Returns `None` if the pointers were not saved.
+	#[inline(always)]
+	pub fn fpu_pointers(&self, save_variant: X87SaveVariant, extended_state_information: &ExtendedStateInformation) -> Option<FpuPointers>
+	{
+		self.x87_state_part_1.fpu_pointers(save_variant, extended_state_information)
+	}
+
+	/// A view of this area as state captured from a 32-bit process; `save_variant` is the variant of instruction that saved this area.
+	#[inline(always)]
+	pub fn compatibility_mode_state(&self, save_variant: X87SaveVariant) -> CompatibilityModeState<'_>
+	{
+		CompatibilityModeState::from_fxsave_area(self, save_variant)
+	}
+
+	/// Validates the `MXCSR` register value against `mxcsr_register_mask_of_current_processor` (see `MxcsrRegisterValue::mxcsr_register_mask_of_current_processor()`), so that `restore()` will not cause a general-protection fault (`#GP`).
+	///
+	/// The `mxcsr_register_mask` field of this area is not used, as it may have been saved on a different processor.
+	///
+	/// Returns the reserved bits that are set as an error.
+	#[inline(always)]
+	pub fn validate_mxcsr_register_value(&self, mxcsr_register_mask_of_current_processor: u32) -> Result<(), u32>
+	{
+		self.sse_state_part_1.mxcsr_register_value.validate(mxcsr_register_mask_of_current_processor)
+	}
+
 	/// `x87` state.
 	///
 	/// The `x87` state is in two non-contiguous areas ('parts').
@@ -26,6 +26,15 @@ pub struct SseStatePart1
 
 impl SseStatePart1
 {
+	/// Validates `mxcsr_register_value` against `mxcsr_register_mask`; see `MxcsrRegisterValue::validate()`.
+	///
+	/// Returns the reserved bits that are set as an error.
+	#[inline(always)]
+	pub fn validate_mxcsr_register_value(&self) -> Result<(), u32>
+	{
+		self.mxcsr_register_value.validate(self.mxcsr_register_mask)
+	}
+
 	/// Set current value of the MXCSR register.
 	///
 	/// Only affects the current thread.
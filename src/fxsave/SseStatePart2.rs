@@ -107,3 +107,36 @@ pub struct SseStatePart2
 	/// These fields are used only in 64-bit mode; in other modes they are neither restored (read) or written to.
 	pub xmm15: SseXmmRegisterValue,
 }
+
+impl SseStatePart2
+{
+	/// Stored value of SSE XMM register `XMMn`.
+	///
+	/// `n` must be less than 16.
+	#[inline(always)]
+	pub fn xmm(&self, n: u8) -> &SseXmmRegisterValue
+	{
+		&self.registers()[n as usize]
+	}
+
+	/// Stored value of SSE XMM register `XMMn`.
+	///
+	/// `n` must be less than 16.
+	#[inline(always)]
+	pub fn xmm_mut(&mut self, n: u8) -> &mut SseXmmRegisterValue
+	{
+		&mut self.registers_mut()[n as usize]
+	}
+
+	#[inline(always)]
+	fn registers(&self) -> &[SseXmmRegisterValue; 16]
+	{
+		unsafe { & * (self as *const Self as *const [SseXmmRegisterValue; 16]) }
+	}
+
+	#[inline(always)]
+	fn registers_mut(&mut self) -> &mut [SseXmmRegisterValue; 16]
+	{
+		unsafe { &mut * (self as *mut Self as *mut [SseXmmRegisterValue; 16]) }
+	}
+}
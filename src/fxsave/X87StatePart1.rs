@@ -10,7 +10,7 @@
 ///
 /// This layout can also be restored using the legacy instruction `FLDENV` (restore FPU environment).
 #[derive(Default, Debug, Clone)]
-#[repr(C, align(16))]
+#[repr(C)]
 pub struct X87StatePart1
 {
 	/// x87 FPU Control Word, `FCW`.
@@ -49,3 +49,91 @@ pub struct X87StatePart1
 	/// Part of the `x87` state.
 	pub fpu_data_pointer_offset: FloatingPointUnitDataPointerOffset,
 }
+
+impl X87StatePart1
+{
+	/// The initial state, as loaded by `FINIT` or by `XRSTOR` if the `x87` state component is not present.
+	///
+	/// The `FCW` is `0x037F`, all x87 FPU data registers are empty and everything else is zero.
+	#[inline(always)]
+	pub fn initial() -> Self
+	{
+		Self
+		{
+			fpu_control_word: X87FloatingPointUnitControlWord::Initial,
+			.. Self::default()
+		}
+	}
+
+	/// The x87 FPU instruction pointer (`FIP`) and data pointer (`FDP`), interpreted for the variant of instruction that saved this state.
+	///
+	/// Returns `None` if the processor did not save the pointers because no unmasked `x87` exception was pending (see `ExtendedStateInformation::fpu_error_pointers_are_meaningful_after_non_exception_save()`).
+	///
+	/// Selectors are reported as absent if the processor deprecates `FCS` and `FDS`; `FDP` is reported as absent if the processor only updates it on unmasked `x87` exceptions and none was pending.
+	#[inline(always)]
+	pub fn fpu_pointers(&self, save_variant: X87SaveVariant, extended_state_information: &ExtendedStateInformation) -> Option<FpuPointers>
+	{
+		let exception_pending = self.fpu_status_word.exception_summary_status();
+		if !exception_pending && !extended_state_information.fpu_error_pointers_are_meaningful_after_non_exception_save()
+		{
+			return None
+		}
+
+		let fpu_data_pointer_is_present = exception_pending || !extended_state_information.fpu_data_pointer_only_updated_on_x87_exceptions;
+		let selectors_are_present = !extended_state_information.fpu_cs_and_fpu_ds_are_deprecated;
+
+		let fpu_pointers = if save_variant.saves_64_bit_offsets()
+		{
+			let instruction_pointer = unsafe { self.fpu_instruction_pointer_offset.mode_64_bit_with_rex_w };
+			let data_pointer = unsafe { self.fpu_data_pointer_offset.mode_64_bit_with_rex_w };
+
+			FpuPointers::Offsets64Bit
+			{
+				fpu_instruction_pointer_offset: instruction_pointer.instruction_pointer_offset,
+				fpu_data_pointer_offset: if fpu_data_pointer_is_present
+				{
+					Some(data_pointer.data_pointer_offset)
+				}
+				else
+				{
+					None
+				},
+			}
+		}
+		else
+		{
+			let instruction_pointer = unsafe { self.fpu_instruction_pointer_offset.mode_32_bit };
+			let data_pointer = unsafe { self.fpu_data_pointer_offset.mode_32_bit };
+
+			FpuPointers::Offsets32BitWithSelectors
+			{
+				fpu_instruction_pointer_offset: instruction_pointer.instruction_pointer_offset,
+				fpu_instruction_pointer_selector: if selectors_are_present
+				{
+					Some(instruction_pointer.instruction_pointer_selector)
+				}
+				else
+				{
+					None
+				},
+				fpu_data_pointer_offset: if fpu_data_pointer_is_present
+				{
+					Some(data_pointer.data_pointer_offset)
+				}
+				else
+				{
+					None
+				},
+				fpu_data_pointer_selector: if fpu_data_pointer_is_present && selectors_are_present
+				{
+					Some(data_pointer.data_pointer_selector)
+				}
+				else
+				{
+					None
+				},
+			}
+		};
+		Some(fpu_pointers)
+	}
+}
@@ -51,3 +51,40 @@ pub struct X87StatePart2
 	/// Part of the `x87` state.
 	pub st7_or_mm7: FloatingPointOrMMRegisterValue,
 }
+
+impl X87StatePart2
+{
+	/// Stored value of floating pointer register `ST(i)` or legacy MMX register `MMi`.
+	///
+	/// Registers are stored in stack order, not in physical register order.
+	///
+	/// `i` must be less than 8.
+	#[inline(always)]
+	pub fn st(&self, i: u8) -> &FloatingPointOrMMRegisterValue
+	{
+		&self.registers()[i as usize]
+	}
+
+	/// Stored value of floating pointer register `ST(i)` or legacy MMX register `MMi`.
+	///
+	/// Registers are stored in stack order, not in physical register order.
+	///
+	/// `i` must be less than 8.
+	#[inline(always)]
+	pub fn st_mut(&mut self, i: u8) -> &mut FloatingPointOrMMRegisterValue
+	{
+		&mut self.registers_mut()[i as usize]
+	}
+
+	#[inline(always)]
+	fn registers(&self) -> &[FloatingPointOrMMRegisterValue; 8]
+	{
+		unsafe { & * (self as *const Self as *const [FloatingPointOrMMRegisterValue; 8]) }
+	}
+
+	#[inline(always)]
+	fn registers_mut(&mut self) -> &mut [FloatingPointOrMMRegisterValue; 8]
+	{
+		unsafe { &mut * (self as *mut Self as *mut [FloatingPointOrMMRegisterValue; 8]) }
+	}
+}
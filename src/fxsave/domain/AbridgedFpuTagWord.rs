@@ -10,12 +10,32 @@
 /// * For each `j`, `0 ≤ j ≤ 7`, `FXRSTOR` establishes the tag value for x87 FPU data register `STj` as follows:-
 ///   * If bit `j` is `0`, the tag for `STj` in the tag register for that data register is marked empty
 ///   * Otherwise, the x87 FPU sets the tag for `STj` based on the value being loaded into that register (see below).
+///
+/// Despite the naming used by Intel, `j` is the index of the physical x87 FPU data register `Rj`, not of the stack register `ST(j)`; see `X87RegisterStack` for the mapping between them.
 #[derive(Default, Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[repr(transparent)]
 pub struct AbridgedFpuTagWord(u8);
 
 impl AbridgedFpuTagWord
 {
+	/// Creates a new instance.
+	///
+	/// Bit `j` is set if physical x87 FPU data register `Rj` is not empty.
+	#[inline(always)]
+	pub const fn new(value: u8) -> Self
+	{
+		AbridgedFpuTagWord(value)
+	}
+
+	/// Raw value.
+	///
+	/// Bit `j` is set if physical x87 FPU data register `Rj` is not empty.
+	#[inline(always)]
+	pub fn value(self) -> u8
+	{
+		self.0
+	}
+
 	/// FPU data register `STj` has an empty tag.
 	///
 	/// `j` must be between 0 and 7 inclusive.
@@ -24,7 +44,7 @@ impl AbridgedFpuTagWord
 	{
 		debug_assert!(j < 8, "j `{}` is greater than 7", j);
 
-		self.0 & (1 << j) != 0
+		self.0 & (1 << j) == 0
 	}
 
 	/// FPU data register `ST0` has an empty tag.
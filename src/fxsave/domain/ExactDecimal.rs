@@ -0,0 +1,119 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// An arbitrary precision unsigned integer, held as little endian limbs in base 10⁹, used to produce exact decimal expansions of binary floating point values.
+struct ExactDecimal
+{
+	limbs: Vec<u32>,
+}
+
+impl ExactDecimal
+{
+	const LimbBase: u64 = 1_000_000_000;
+
+	const LimbDigits: usize = 9;
+
+	/// Exact decimal expansion of `significand × 2^exponent`, as an integer part and a fractional part (without trailing zeros; empty if there is no fractional part).
+	#[inline(always)]
+	fn exact_decimal(significand: u64, exponent: i32) -> (String, String)
+	{
+		let mut this = Self::new(significand);
+
+		if exponent >= 0
+		{
+			this.multiply_by_power(2, 29, exponent as u32);
+			(this.to_string(), String::new())
+		}
+		else
+		{
+			// `significand × 2^-k` is `significand × 5^k × 10^-k`.
+			let number_of_fractional_digits = (-exponent) as usize;
+			this.multiply_by_power(5, 13, number_of_fractional_digits as u32);
+
+			let mut digits = this.to_string();
+			if digits.len() <= number_of_fractional_digits
+			{
+				let leading_zeros = number_of_fractional_digits + 1 - digits.len();
+				digits.insert_str(0, &"0".repeat(leading_zeros));
+			}
+
+			let mut fractional_part = digits.split_off(digits.len() - number_of_fractional_digits);
+			let length_without_trailing_zeros = fractional_part.trim_end_matches('0').len();
+			fractional_part.truncate(length_without_trailing_zeros);
+			(digits, fractional_part)
+		}
+	}
+
+	#[inline(always)]
+	fn new(value: u64) -> Self
+	{
+		let mut limbs = Vec::with_capacity(4);
+		let mut value = value;
+		loop
+		{
+			limbs.push((value % Self::LimbBase) as u32);
+			value /= Self::LimbBase;
+			if value == 0
+			{
+				break
+			}
+		}
+
+		Self
+		{
+			limbs
+		}
+	}
+
+	/// Multiplies by `base^power`, in steps of `base^maximum_step` (which must fit in an `u32`).
+	#[inline(always)]
+	fn multiply_by_power(&mut self, base: u32, maximum_step: u32, power: u32)
+	{
+		let mut remaining = power;
+		while remaining != 0
+		{
+			let step = if remaining > maximum_step
+			{
+				maximum_step
+			}
+			else
+			{
+				remaining
+			};
+			self.multiply(base.pow(step));
+			remaining -= step;
+		}
+	}
+
+	#[inline(always)]
+	fn multiply(&mut self, factor: u32)
+	{
+		let mut carry = 0u64;
+		for limb in self.limbs.iter_mut()
+		{
+			let product = (*limb as u64) * (factor as u64) + carry;
+			*limb = (product % Self::LimbBase) as u32;
+			carry = product / Self::LimbBase;
+		}
+
+		while carry != 0
+		{
+			self.limbs.push((carry % Self::LimbBase) as u32);
+			carry /= Self::LimbBase;
+		}
+	}
+
+	#[inline(always)]
+	fn to_string(&self) -> String
+	{
+		let mut limbs = self.limbs.iter().rev();
+		let mut string = String::with_capacity(self.limbs.len() * Self::LimbDigits);
+		string.push_str(&format!("{}", limbs.next().unwrap()));
+		for limb in limbs
+		{
+			string.push_str(&format!("{:09}", limb));
+		}
+		string
+	}
+}
@@ -0,0 +1,93 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// Classification of a x87 80-bit double extended-precision floating point value, as made by the `FXAM` instruction.
+///
+/// See Section 8.2.2 and Table 8-3 of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
+///
+/// `FXAM` also reports a register as empty; this is a property of the tag word rather than the value.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum ExtendedPrecisionFloatingPointClass
+{
+	/// Zero (biased exponent, integer bit and fraction all zero).
+	Zero,
+
+	/// Denormal (biased exponent zero, integer bit zero, fraction non-zero).
+	Denormal,
+
+	/// Pseudo-denormal (biased exponent zero, integer bit one).
+	///
+	/// Only supported as an operand; never generated by the x87 FPU.
+	/// `FXAM` reports these as a denormal.
+	PseudoDenormal,
+
+	/// Normal (biased exponent neither zero nor all ones, integer bit one).
+	Normal,
+
+	/// Infinity (biased exponent all ones, integer bit one, fraction zero).
+	Infinity,
+
+	/// Quiet NaN, `QNaN` (biased exponent all ones, integer bit one, most significant fraction bit one).
+	QuietNaN,
+
+	/// Signaling NaN, `SNaN` (biased exponent all ones, integer bit one, most significant fraction bit zero, fraction non-zero).
+	SignalingNaN,
+
+	/// Unnormal (biased exponent neither zero nor all ones, integer bit zero).
+	///
+	/// Unsupported.
+	Unnormal,
+
+	/// Pseudo-infinity (biased exponent all ones, integer bit zero, fraction zero).
+	///
+	/// Unsupported.
+	PseudoInfinity,
+
+	/// Pseudo-NaN (biased exponent all ones, integer bit zero, fraction non-zero).
+	///
+	/// Unsupported.
+	PseudoNaN,
+}
+
+impl ExtendedPrecisionFloatingPointClass
+{
+	/// Is this an encoding the x87 FPU does not support, and for which `FXAM` reports 'unsupported' (condition codes `C3`, `C2` and `C0` all clear)?
+	///
+	/// Using such a value as an operand causes an invalid-arithmetic-operand exception.
+	#[inline(always)]
+	pub fn is_unsupported(self) -> bool
+	{
+		use self::ExtendedPrecisionFloatingPointClass::*;
+
+		matches!(self, Unnormal | PseudoInfinity | PseudoNaN)
+	}
+
+	/// Is this a NaN (quiet or signaling; pseudo-NaNs are not NaNs)?
+	#[inline(always)]
+	pub fn is_nan(self) -> bool
+	{
+		use self::ExtendedPrecisionFloatingPointClass::*;
+
+		matches!(self, QuietNaN | SignalingNaN)
+	}
+
+	/// Condition codes `C3`, `C2` and `C0` as set by `FXAM` for a non-empty register, as bits 2, 1 and 0 respectively.
+	///
+	/// `C1` is set by `FXAM` to the sign of the value.
+	#[inline(always)]
+	pub fn fxam_condition_codes(self) -> u8
+	{
+		use self::ExtendedPrecisionFloatingPointClass::*;
+
+		match self
+		{
+			Unnormal | PseudoInfinity | PseudoNaN => 0b000,
+			QuietNaN | SignalingNaN => 0b001,
+			Normal => 0b010,
+			Infinity => 0b011,
+			Zero => 0b100,
+			Denormal | PseudoDenormal => 0b110,
+		}
+	}
+}
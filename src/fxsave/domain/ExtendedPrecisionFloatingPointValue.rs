@@ -0,0 +1,403 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// A x87 80-bit double extended-precision floating point value, decoded.
+///
+/// See Section 4.2.2 and Figure 4-3 of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1, for the layout.
+///
+/// Unlike the single and double precision formats, the integer bit, `J`, of the significand is explicit.
+///
+/// Displays as its exact decimal value (which can be many thousands of digits long).
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ExtendedPrecisionFloatingPointValue
+{
+	/// Sign; `true` if negative.
+	pub sign: bool,
+
+	/// Biased exponent.
+	///
+	/// A 15-bit (`u15`) value.
+	pub biased_exponent: u16,
+
+	/// Significand, comprising the integer bit, `J`, in bit 63 and the fraction in bits 62 to 0.
+	pub significand: u64,
+}
+
+impl From<&[u8; 10]> for ExtendedPrecisionFloatingPointValue
+{
+	#[inline(always)]
+	fn from(bytes: &[u8; 10]) -> Self
+	{
+		let sign_and_exponent = u16::from_le_bytes(*array_ref!(bytes, 8, 2));
+
+		Self
+		{
+			sign: sign_and_exponent & 0x8000 != 0,
+			biased_exponent: sign_and_exponent & Self::MaximumBiasedExponent,
+			significand: u64::from_le_bytes(*array_ref!(bytes, 0, 8)),
+		}
+	}
+}
+
+impl From<f64> for ExtendedPrecisionFloatingPointValue
+{
+	/// Always exact, as every `f64` value can be represented.
+	///
+	/// NaN payloads are preserved (so a signaling NaN remains signaling).
+	#[inline(always)]
+	fn from(value: f64) -> Self
+	{
+		const F64ExponentBias: i32 = 1023;
+		const F64MaximumBiasedExponent: u64 = 0x7FF;
+
+		let bits = value.to_bits();
+		let sign = bits & (1 << 63) != 0;
+		let f64_biased_exponent = (bits >> 52) & F64MaximumBiasedExponent;
+		let f64_fraction = bits & ((1 << 52) - 1);
+
+		let (biased_exponent, significand) = match f64_biased_exponent
+		{
+			0 => if f64_fraction == 0
+			{
+				(0, 0)
+			}
+			else
+			{
+				// f64 denormals are normals in the double extended-precision format.
+				let leading_zeros = f64_fraction.leading_zeros() as i32;
+				let exponent = 1 - F64ExponentBias - (leading_zeros - 11);
+				((exponent + Self::ExponentBias) as u16, f64_fraction << leading_zeros)
+			},
+
+			F64MaximumBiasedExponent => (Self::MaximumBiasedExponent, Self::IntegerBit | (f64_fraction << 11)),
+
+			_ => (((f64_biased_exponent as i32) - F64ExponentBias + Self::ExponentBias) as u16, Self::IntegerBit | (f64_fraction << 11)),
+		};
+
+		Self
+		{
+			sign,
+			biased_exponent,
+			significand,
+		}
+	}
+}
+
+impl Display for ExtendedPrecisionFloatingPointValue
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::ExtendedPrecisionFloatingPointClass::*;
+
+		let sign = if self.sign
+		{
+			"-"
+		}
+		else
+		{
+			""
+		};
+
+		match self.class()
+		{
+			Infinity => write!(f, "{}inf", sign),
+			QuietNaN => write!(f, "{}QNaN(0x{:016X})", sign, self.fraction()),
+			SignalingNaN => write!(f, "{}SNaN(0x{:016X})", sign, self.fraction()),
+			PseudoInfinity => write!(f, "{}pseudo-inf", sign),
+			PseudoNaN => write!(f, "{}pseudo-NaN(0x{:016X})", sign, self.fraction()),
+			Zero => write!(f, "{}0", sign),
+			Denormal | PseudoDenormal | Normal | Unnormal =>
+			{
+				let (integer_part, fractional_part) = ExactDecimal::exact_decimal(self.significand, self.exponent_of_significand());
+				if fractional_part.is_empty()
+				{
+					write!(f, "{}{}", sign, integer_part)
+				}
+				else
+				{
+					write!(f, "{}{}.{}", sign, integer_part, fractional_part)
+				}
+			}
+		}
+	}
+}
+
+impl ExtendedPrecisionFloatingPointValue
+{
+	/// Exponent bias.
+	pub const ExponentBias: i32 = 16383;
+
+	/// Maximum biased exponent (all ones); used for infinities and NaNs.
+	pub const MaximumBiasedExponent: u16 = 0x7FFF;
+
+	/// The integer bit, `J`, of the significand.
+	pub const IntegerBit: u64 = 1 << 63;
+
+	const QuietBit: u64 = 1 << 62;
+
+	/// The real indefinite, the quiet NaN generated by the x87 FPU for a masked invalid-operation exception.
+	pub const RealIndefinite: Self = Self
+	{
+		sign: true,
+		biased_exponent: Self::MaximumBiasedExponent,
+		significand: Self::IntegerBit | Self::QuietBit,
+	};
+
+	/// Encodes as 10 bytes (little endian), as stored in `FloatingPointOrMMRegisterValue`.
+	#[inline(always)]
+	pub fn to_bytes(self) -> [u8; 10]
+	{
+		let sign_and_exponent = if self.sign
+		{
+			0x8000 | self.biased_exponent
+		}
+		else
+		{
+			self.biased_exponent
+		};
+
+		let mut bytes = [0u8; 10];
+		*array_mut_ref!(bytes, 0, 8) = self.significand.to_le_bytes();
+		*array_mut_ref!(bytes, 8, 2) = sign_and_exponent.to_le_bytes();
+		bytes
+	}
+
+	/// Integer bit, `J`.
+	#[inline(always)]
+	pub fn integer_bit(self) -> bool
+	{
+		self.significand & Self::IntegerBit != 0
+	}
+
+	/// Fraction.
+	///
+	/// A 63-bit (`u63`) value.
+	#[inline(always)]
+	pub fn fraction(self) -> u64
+	{
+		self.significand & !Self::IntegerBit
+	}
+
+	/// Unbiased exponent, as interpreted by the x87 FPU.
+	///
+	/// Denormals and pseudo-denormals, with a biased exponent of zero, are interpreted as if it were one.
+	#[inline(always)]
+	pub fn unbiased_exponent(self) -> i32
+	{
+		if self.biased_exponent == 0
+		{
+			1 - Self::ExponentBias
+		}
+		else
+		{
+			(self.biased_exponent as i32) - Self::ExponentBias
+		}
+	}
+
+	/// Classifies this value as `FXAM` does.
+	#[inline(always)]
+	pub fn class(self) -> ExtendedPrecisionFloatingPointClass
+	{
+		use self::ExtendedPrecisionFloatingPointClass::*;
+
+		let integer_bit = self.integer_bit();
+		let fraction = self.fraction();
+
+		match self.biased_exponent
+		{
+			0 => if integer_bit
+			{
+				PseudoDenormal
+			}
+			else if fraction == 0
+			{
+				Zero
+			}
+			else
+			{
+				Denormal
+			},
+
+			Self::MaximumBiasedExponent => if !integer_bit
+			{
+				if fraction == 0
+				{
+					PseudoInfinity
+				}
+				else
+				{
+					PseudoNaN
+				}
+			}
+			else if fraction == 0
+			{
+				Infinity
+			}
+			else if fraction & Self::QuietBit != 0
+			{
+				QuietNaN
+			}
+			else
+			{
+				SignalingNaN
+			},
+
+			_ => if integer_bit
+			{
+				Normal
+			}
+			else
+			{
+				Unnormal
+			},
+		}
+	}
+
+	/// Is this the real indefinite?
+	#[inline(always)]
+	pub fn is_real_indefinite(self) -> bool
+	{
+		self == Self::RealIndefinite
+	}
+
+	/// Converts to a `f64` as the `FST` instruction would with all exceptions masked, rounding using `rounding_control`.
+	///
+	/// Thus:-
+	///
+	/// * Signaling NaNs are converted to quiet NaNs;
+	/// * NaN payloads are truncated to their most significant 52 bits;
+	/// * Unsupported encodings (see `ExtendedPrecisionFloatingPointClass::is_unsupported()`) are converted to the real indefinite;
+	/// * Values too large in magnitude are converted to infinity or the largest finite value, depending on `rounding_control`;
+	/// * Values too small in magnitude are converted to denormals, zero or the smallest denormal, depending on `rounding_control`.
+	#[inline(always)]
+	pub fn to_f64(self, rounding_control: RoundingControl) -> f64
+	{
+		use self::ExtendedPrecisionFloatingPointClass::*;
+		use self::RoundingControl::*;
+
+		const F64ExponentBias: i32 = 1023;
+		const F64Infinity: u64 = 0x7FF << 52;
+		const F64QuietBit: u64 = 1 << 51;
+		const F64FractionMask: u64 = F64QuietBit * 2 - 1;
+		const F64LargestFinite: u64 = F64Infinity - 1;
+		const F64RealIndefinite: u64 = (1 << 63) | F64Infinity | F64QuietBit;
+		const F64SignificandBits: i32 = 53;
+		const F64MinimumExponent: i32 = 1 - F64ExponentBias;
+		const F64MaximumExponent: i32 = F64ExponentBias;
+
+		let sign_bit = if self.sign
+		{
+			1 << 63
+		}
+		else
+		{
+			0
+		};
+
+		let magnitude = match self.class()
+		{
+			Zero => 0,
+
+			Infinity => F64Infinity,
+
+			QuietNaN | SignalingNaN => F64Infinity | F64QuietBit | (self.fraction() >> 11),
+
+			Unnormal | PseudoInfinity | PseudoNaN => return f64::from_bits(F64RealIndefinite),
+
+			Denormal | PseudoDenormal | Normal =>
+			{
+				// Normalize, so that `significand` has its most significant bit set and the value is `significand × 2^(exponent - 63)`.
+				let leading_zeros = self.significand.leading_zeros() as i32;
+				let significand = self.significand << leading_zeros;
+				let mut exponent = self.unbiased_exponent() - leading_zeros;
+
+				let round_away_from_zero_when_inexact = match rounding_control
+				{
+					RoundToNearest => None,
+					RoundTowardZero => Some(false),
+					RoundDownTowardNegativeInfinity => Some(self.sign),
+					RoundUpTowardPositiveInfinity => Some(!self.sign),
+				};
+
+				let overflow = |round_away_from_zero_when_inexact: Option<bool>| match round_away_from_zero_when_inexact
+				{
+					None | Some(true) => F64Infinity,
+					Some(false) => F64LargestFinite,
+				};
+
+				if exponent > F64MaximumExponent
+				{
+					overflow(round_away_from_zero_when_inexact)
+				}
+				else
+				{
+					let precision = if exponent >= F64MinimumExponent
+					{
+						F64SignificandBits
+					}
+					else
+					{
+						F64SignificandBits - (F64MinimumExponent - exponent)
+					};
+					let bits_to_drop = 64 - precision;
+
+					let (kept, half, sticky) = if bits_to_drop > 64
+					{
+						(0, false, true)
+					}
+					else if bits_to_drop == 64
+					{
+						(0, significand & Self::IntegerBit != 0, significand & !Self::IntegerBit != 0)
+					}
+					else
+					{
+						let half_bit = 1u64 << (bits_to_drop - 1);
+						(significand >> bits_to_drop, significand & half_bit != 0, significand & (half_bit - 1) != 0)
+					};
+
+					let round_up = match round_away_from_zero_when_inexact
+					{
+						None => half && (sticky || kept & 1 != 0),
+						Some(away) => away && (half || sticky),
+					};
+
+					let mut kept = kept + (round_up as u64);
+
+					if exponent >= F64MinimumExponent
+					{
+						if kept == (1 << F64SignificandBits)
+						{
+							kept >>= 1;
+							exponent += 1;
+						}
+
+						if exponent > F64MaximumExponent
+						{
+							overflow(round_away_from_zero_when_inexact)
+						}
+						else
+						{
+							(((exponent + F64ExponentBias) as u64) << 52) | (kept & F64FractionMask)
+						}
+					}
+					else
+					{
+						// A denormal; rounding up to `1 << 52` correctly produces the smallest normal.
+						kept
+					}
+				}
+			}
+		};
+
+		f64::from_bits(sign_bit | magnitude)
+	}
+
+	/// The value is `significand × 2^exponent_of_significand()`.
+	#[inline(always)]
+	fn exponent_of_significand(self) -> i32
+	{
+		self.unbiased_exponent() - 63
+	}
+}
@@ -45,4 +45,18 @@ impl FloatingPointOrMMRegisterValue
 	{
 		&mut self.register_value
 	}
+
+	/// Floating point value, decoded.
+	#[inline(always)]
+	pub fn extended_precision_floating_point_value(&self) -> ExtendedPrecisionFloatingPointValue
+	{
+		ExtendedPrecisionFloatingPointValue::from(&self.register_value)
+	}
+
+	/// Sets the floating point value.
+	#[inline(always)]
+	pub fn set_extended_precision_floating_point_value(&mut self, value: ExtendedPrecisionFloatingPointValue)
+	{
+		self.register_value = value.to_bytes()
+	}
 }
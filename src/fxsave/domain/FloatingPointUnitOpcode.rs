@@ -14,12 +14,29 @@ pub struct FloatingPointUnitOpcode(u16);
 
 impl FloatingPointUnitOpcode
 {
+	/// Mask of the 11 bits used.
+	pub const Mask: u16 = 0b0000_0111_1111_1111;
+
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u16) -> Self
+	{
+		FloatingPointUnitOpcode(value)
+	}
+
+	/// Raw value.
+	#[inline(always)]
+	pub fn value(self) -> u16
+	{
+		self.0
+	}
+
 	/// The first opcode byte.
 	#[inline(always)]
 	pub fn first_opcode_byte(self) -> u8
 	{
 		const upper_5_bit_of_first_opcode: u8 = 0b11011 << 3;
-		let lower_3_bits_of_first_opcode = (self.0 & 0b0000_0111_0000_0000 >> 8) as u8;
+		let lower_3_bits_of_first_opcode = ((self.0 & 0b0000_0111_0000_0000) >> 8) as u8;
 
 		upper_5_bit_of_first_opcode | lower_3_bits_of_first_opcode
 	}
@@ -30,4 +47,11 @@ impl FloatingPointUnitOpcode
 	{
 		(self.0 & 0b1111_1111) as u8
 	}
+
+	/// Decodes the instruction, or `None` if the encoding is reserved or undocumented.
+	#[inline(always)]
+	pub fn instruction(self) -> Option<X87Instruction>
+	{
+		X87Instruction::decode(self)
+	}
 }
@@ -0,0 +1,73 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The x87 FPU instruction pointer (`FIP`) and data pointer (`FDP`), interpreted for the variant of instruction that saved them.
+///
+/// See Section 8.1.8 (x87 FPU Instruction and Data (Operand) Pointers) of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FpuPointers
+{
+	/// Saved by `FXSAVE` or `XSAVE` (see `X87SaveVariant`): 32-bit offsets and 16-bit selectors.
+	Offsets32BitWithSelectors
+	{
+		/// x87 FPU Instruction Pointer Offset, `FIP`.
+		fpu_instruction_pointer_offset: u32,
+
+		/// x87 FPU Instruction Pointer Selector, `FCS`.
+		///
+		/// `None` if the processor deprecates `FCS` and `FDS`.
+		fpu_instruction_pointer_selector: Option<u16>,
+
+		/// x87 FPU Data Pointer Offset, `FDP`.
+		///
+		/// `None` if the processor only updates `FDP` on unmasked `x87` exceptions and none is pending.
+		fpu_data_pointer_offset: Option<u32>,
+
+		/// x87 FPU Data Pointer Selector, `FDS`.
+		///
+		/// `None` if the processor deprecates `FCS` and `FDS`, or if `fpu_data_pointer_offset` is `None`.
+		fpu_data_pointer_selector: Option<u16>,
+	},
+
+	/// Saved by `FXSAVE64` or `XSAVE64` (see `X87SaveVariant`): 64-bit offsets.
+	Offsets64Bit
+	{
+		/// x87 FPU Instruction Pointer Offset, `FIP`.
+		fpu_instruction_pointer_offset: u64,
+
+		/// x87 FPU Data Pointer Offset, `FDP`.
+		///
+		/// `None` if the processor only updates `FDP` on unmasked `x87` exceptions and none is pending.
+		fpu_data_pointer_offset: Option<u64>,
+	},
+}
+
+impl FpuPointers
+{
+	/// x87 FPU Instruction Pointer Offset, `FIP`, widened to 64 bits.
+	#[inline(always)]
+	pub fn fpu_instruction_pointer_offset(&self) -> u64
+	{
+		use self::FpuPointers::*;
+
+		match *self
+		{
+			Offsets32BitWithSelectors { fpu_instruction_pointer_offset, .. } => fpu_instruction_pointer_offset as u64,
+			Offsets64Bit { fpu_instruction_pointer_offset, .. } => fpu_instruction_pointer_offset,
+		}
+	}
+
+	/// x87 FPU Data Pointer Offset, `FDP`, widened to 64 bits.
+	#[inline(always)]
+	pub fn fpu_data_pointer_offset(&self) -> Option<u64>
+	{
+		use self::FpuPointers::*;
+
+		match *self
+		{
+			Offsets32BitWithSelectors { fpu_data_pointer_offset, .. } => fpu_data_pointer_offset.map(|fpu_data_pointer_offset| fpu_data_pointer_offset as u64),
+			Offsets64Bit { fpu_data_pointer_offset, .. } => fpu_data_pointer_offset,
+		}
+	}
+}
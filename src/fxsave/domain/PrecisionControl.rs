@@ -18,18 +18,18 @@
 /// * `0b10`: Double Precision (53 bits).
 /// * `0b11`: Double Extended Precision (64 bits)
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
-#[repr(u16)]
+#[repr(u8)]
 pub enum PrecisionControl
 {
 	/// Single Precision (24 bits).
-	SinglePrecision = 0b00_0000_0000,
+	SinglePrecision = 0b00,
 
 	/// Reserved.
-	Reserved = 0b01_0000_0000,
+	Reserved = 0b01,
 
 	/// Double Precision (53 bits).
-	DoublePrecision = 0b10_0000_0000,
+	DoublePrecision = 0b10,
 
 	/// Double Extended Precision (64 bits).
-	DoubleExtendedPrecision = 0b11_0000_0000,
+	DoubleExtendedPrecision = 0b11,
 }
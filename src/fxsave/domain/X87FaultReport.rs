@@ -0,0 +1,102 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// A report of the last x87 FPU non-control instruction that incurred an unmasked exception, as recorded in the x87 FPU opcode (`FOP`), instruction pointer (`FIP`) and data pointer (`FDP`) registers.
+///
+/// Displays as, eg, ``faulting x87 instruction `fdiv st(0), st(3)` at FIP 0x401000``.
+///
+/// See Section 8.1.8 (x87 FPU Instruction and Data (Operand) Pointers) and Section 8.1.9 (Last Instruction Opcode) of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct X87FaultReport
+{
+	/// x87 FPU Opcode, `FOP`.
+	pub fpu_opcode: FloatingPointUnitOpcode,
+
+	/// Decoded instruction, or `None` if `fpu_opcode` is a reserved or undocumented encoding.
+	pub instruction: Option<X87Instruction>,
+
+	/// Address of the instruction, from the x87 FPU instruction pointer (`FIP`).
+	pub fpu_instruction_pointer: u64,
+
+	/// Address of the memory operand, from the x87 FPU data pointer (`FDP`), or `None` if the instruction does not have a memory operand.
+	pub fpu_data_pointer: Option<u64>,
+}
+
+impl Display for X87FaultReport
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		match self.instruction
+		{
+			None => write!(f, "faulting x87 instruction with unknown opcode 0x{:03X} at FIP 0x{:X}", self.fpu_opcode.value(), self.fpu_instruction_pointer)?,
+			Some(instruction) => write!(f, "faulting x87 instruction `{}` at FIP 0x{:X}", instruction, self.fpu_instruction_pointer)?,
+		}
+
+		if let Some(fpu_data_pointer) = self.fpu_data_pointer
+		{
+			write!(f, ", memory operand at FDP 0x{:X}", fpu_data_pointer)?
+		}
+
+		Ok(())
+	}
+}
+
+impl X87FaultReport
+{
+	/// Creates a new instance.
+	///
+	/// `fpu_data_pointer` is discarded unless the decoded instruction has a memory operand.
+	#[inline(always)]
+	pub fn new(fpu_opcode: FloatingPointUnitOpcode, fpu_instruction_pointer: u64, fpu_data_pointer: u64) -> Self
+	{
+		Self::new_with_optional_data_pointer(fpu_opcode, fpu_instruction_pointer, Some(fpu_data_pointer))
+	}
+
+	/// Creates a new instance from `FOP` and the instruction and data pointers interpreted for the variant of instruction that saved them (see `X87StatePart1::fpu_pointers()`).
+	///
+	/// Selectors, if any, are ignored.
+	#[inline(always)]
+	pub fn from_fpu_pointers(fpu_opcode: FloatingPointUnitOpcode, fpu_pointers: &FpuPointers) -> Self
+	{
+		Self::new_with_optional_data_pointer(fpu_opcode, fpu_pointers.fpu_instruction_pointer_offset(), fpu_pointers.fpu_data_pointer_offset())
+	}
+
+	/// Creates a new instance from `x87` state saved by `FXSAVE64` or `XSAVE64` (as `FXSaveArea::save()` and `XSaveArea::save()` do on x86_64), which store 64-bit `FIP` and `FDP` offsets.
+	///
+	/// Prefer `from_fpu_pointers()`, which also accounts for processors that do not always update `FDP`.
+	#[inline(always)]
+	pub fn from_64_bit_save(x87_state_part_1: &X87StatePart1) -> Self
+	{
+		let fpu_instruction_pointer = unsafe { x87_state_part_1.fpu_instruction_pointer_offset.mode_64_bit_with_rex_w.instruction_pointer_offset };
+		let fpu_data_pointer = unsafe { x87_state_part_1.fpu_data_pointer_offset.mode_64_bit_with_rex_w.data_pointer_offset };
+		Self::new(x87_state_part_1.fpu_opcode, fpu_instruction_pointer, fpu_data_pointer)
+	}
+
+	#[inline(always)]
+	fn new_with_optional_data_pointer(fpu_opcode: FloatingPointUnitOpcode, fpu_instruction_pointer: u64, fpu_data_pointer: Option<u64>) -> Self
+	{
+		let instruction = fpu_opcode.instruction();
+		let has_memory_operand = match instruction
+		{
+			None => false,
+			Some(instruction) => instruction.has_memory_operand(),
+		};
+
+		Self
+		{
+			fpu_opcode,
+			instruction,
+			fpu_instruction_pointer,
+			fpu_data_pointer: if has_memory_operand
+			{
+				fpu_data_pointer
+			}
+			else
+			{
+				None
+			},
+		}
+	}
+}
@@ -9,6 +9,27 @@ pub struct X87FloatingPointUnitControlWord(u16);
 
 impl X87FloatingPointUnitControlWord
 {
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u16) -> Self
+	{
+		X87FloatingPointUnitControlWord(value)
+	}
+
+	/// Raw value.
+	#[inline(always)]
+	pub fn value(self) -> u16
+	{
+		self.0
+	}
+
+	/// The initial value, `0x037F`, as set by `FINIT`: all exceptions masked, 64-bit extended precision and round to nearest.
+	pub const Initial: Self = X87FloatingPointUnitControlWord(0x037F);
+
+	const PrecisionControlMask: u16 = 0b0000_0011_0000_0000;
+
+	const RoundingControlMask: u16 = 0b0000_1100_0000_0000;
+
 	/// Reads the control word after raising any pending unmasked floating point exceptions.
 	///
 	/// Uses the `FSTCW` instruction; see <https://github.com/HJLebbink/asm-dude/wiki/FSTCW_FNSTCW>.
@@ -76,14 +97,14 @@ impl X87FloatingPointUnitControlWord
 	/// Uses the `FLDCW` instruction; see <https://github.com/HJLebbink/asm-dude/wiki/FLDCW>.
 	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 	#[inline(always)]
-	pub fn restore(mut self)
+	pub fn restore(self)
 	{
-		let control_word_pointer = &mut self.0;
+		let control_word_pointer = &self.0;
 		unsafe
 		{
 			asm!
 			(
-				"fnstcw $0"
+				"fldcw $0"
 				:
 					// Output constraints.
 				:
@@ -109,6 +130,13 @@ impl X87FloatingPointUnitControlWord
 		self.0 & 0b0000_0000_0000_0001 != 0
 	}
 
+	/// Sets Invalid Operation, `IM`.
+	#[inline(always)]
+	pub fn set_exception_flag_mask_bit_invalid_operation(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0000_0000_0001, value)
+	}
+
 	/// Denormal Operation, `DM`.
 	///
 	/// One of the 6 exception flag mask bits.
@@ -120,6 +148,13 @@ impl X87FloatingPointUnitControlWord
 		self.0 & 0b0000_0000_0000_0010 != 0
 	}
 
+	/// Sets Denormal Operation, `DM`.
+	#[inline(always)]
+	pub fn set_exception_flag_mask_bit_denormal_operation(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0000_0000_0010, value)
+	}
+
 	/// Zero Divide, `ZM`.
 	///
 	/// One of the 6 exception flag mask bits.
@@ -131,6 +166,13 @@ impl X87FloatingPointUnitControlWord
 		self.0 & 0b0000_0000_0000_0100 != 0
 	}
 
+	/// Sets Zero Divide, `ZM`.
+	#[inline(always)]
+	pub fn set_exception_flag_mask_bit_zero_divide(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0000_0000_0100, value)
+	}
+
 	/// Overflow, `OM`.
 	///
 	/// One of the 6 exception flag mask bits.
@@ -142,6 +184,13 @@ impl X87FloatingPointUnitControlWord
 		self.0 & 0b0000_0000_0000_1000 != 0
 	}
 
+	/// Sets Overflow, `OM`.
+	#[inline(always)]
+	pub fn set_exception_flag_mask_bit_overflow(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0000_0000_1000, value)
+	}
+
 	/// Underflow, `UM`.
 	///
 	/// One of the 6 exception flag mask bits.
@@ -153,6 +202,13 @@ impl X87FloatingPointUnitControlWord
 		self.0 & 0b0000_0000_0001_0000 != 0
 	}
 
+	/// Sets Underflow, `UM`.
+	#[inline(always)]
+	pub fn set_exception_flag_mask_bit_underflow(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0000_0001_0000, value)
+	}
+
 	/// Precision, `PM`.
 	///
 	/// One of the 6 exception flag mask bits.
@@ -164,11 +220,25 @@ impl X87FloatingPointUnitControlWord
 		self.0 & 0b0000_0000_0010_0000 != 0
 	}
 
+	/// Sets Precision, `PM`.
+	#[inline(always)]
+	pub fn set_exception_flag_mask_bit_precision(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0000_0010_0000, value)
+	}
+
 	/// Precision Control, `PC`.
 	#[inline(always)]
 	pub fn precision_control(self) -> PrecisionControl
 	{
-		unsafe { transmute(self.0 & 0b0000_0011_0000_0000) }
+		unsafe { transmute(((self.0 & Self::PrecisionControlMask) >> 8) as u8) }
+	}
+
+	/// Sets Precision Control, `PC`.
+	#[inline(always)]
+	pub fn set_precision_control(&mut self, precision_control: PrecisionControl)
+	{
+		self.0 = (self.0 & !Self::PrecisionControlMask) | ((precision_control as u16) << 8)
 	}
 
 	/// Rounding Control, `RC`.
@@ -177,7 +247,14 @@ impl X87FloatingPointUnitControlWord
 	#[inline(always)]
 	pub fn rounding_control(self) -> RoundingControl
 	{
-		unsafe { transmute((self.0 & 0b0000_1100_0000_0000 >> 10) as u8) }
+		unsafe { transmute(((self.0 & Self::RoundingControlMask) >> 10) as u8) }
+	}
+
+	/// Sets Rounding Control, `RC`.
+	#[inline(always)]
+	pub fn set_rounding_control(&mut self, rounding_control: RoundingControl)
+	{
+		self.0 = (self.0 & !Self::RoundingControlMask) | ((rounding_control as u16) << 10)
 	}
 
 	/// Infinity Control, `X`, flag.
@@ -188,4 +265,137 @@ impl X87FloatingPointUnitControlWord
 	{
 		self.0 & 0b0001_0000_0000_0000 != 0
 	}
+
+	/// Sets Infinity Control, `X`, flag.
+	#[inline(always)]
+	pub fn set_infinity_control(&mut self, value: bool)
+	{
+		self.set_bits(0b0001_0000_0000_0000, value)
+	}
+
+	#[inline(always)]
+	fn set_bits(&mut self, bits: u16, value: bool)
+	{
+		if value
+		{
+			self.0 |= bits
+		}
+		else
+		{
+			self.0 &= !bits
+		}
+	}
+}
+
+#[cfg(test)]
+mod X87FloatingPointUnitControlWordTests
+{
+	use super::*;
+
+	#[test]
+	fn exception_flag_mask_bits_round_trip()
+	{
+		use self::X87FloatingPointUnitControlWord as W;
+
+		assert_flag_round_trips(W::exception_flag_mask_bit_invalid_operation, W::set_exception_flag_mask_bit_invalid_operation, 0x0001);
+		assert_flag_round_trips(W::exception_flag_mask_bit_denormal_operation, W::set_exception_flag_mask_bit_denormal_operation, 0x0002);
+		assert_flag_round_trips(W::exception_flag_mask_bit_zero_divide, W::set_exception_flag_mask_bit_zero_divide, 0x0004);
+		assert_flag_round_trips(W::exception_flag_mask_bit_overflow, W::set_exception_flag_mask_bit_overflow, 0x0008);
+		assert_flag_round_trips(W::exception_flag_mask_bit_underflow, W::set_exception_flag_mask_bit_underflow, 0x0010);
+		assert_flag_round_trips(W::exception_flag_mask_bit_precision, W::set_exception_flag_mask_bit_precision, 0x0020);
+		assert_flag_round_trips(W::infinity_control, W::set_infinity_control, 0x1000);
+	}
+
+	#[test]
+	fn precision_control_round_trips()
+	{
+		use self::PrecisionControl::*;
+
+		for &precision_control in &[SinglePrecision, Reserved, DoublePrecision, DoubleExtendedPrecision]
+		{
+			let bits = (precision_control as u16) << 8;
+
+			let mut control_word = X87FloatingPointUnitControlWord::new(0x0000);
+			control_word.set_precision_control(precision_control);
+			assert_eq!(control_word.value(), bits);
+			assert_eq!(control_word.precision_control(), precision_control);
+
+			let mut control_word = X87FloatingPointUnitControlWord::new(0xFFFF);
+			control_word.set_precision_control(precision_control);
+			assert_eq!(control_word.value(), 0xFCFF | bits);
+			assert_eq!(control_word.precision_control(), precision_control);
+		}
+	}
+
+	#[test]
+	fn rounding_control_round_trips()
+	{
+		use self::RoundingControl::*;
+
+		for &rounding_control in &[RoundToNearest, RoundDownTowardNegativeInfinity, RoundUpTowardPositiveInfinity, RoundTowardZero]
+		{
+			let bits = (rounding_control as u16) << 10;
+
+			let mut control_word = X87FloatingPointUnitControlWord::new(0x0000);
+			control_word.set_rounding_control(rounding_control);
+			assert_eq!(control_word.value(), bits);
+			assert_eq!(control_word.rounding_control(), rounding_control);
+
+			let mut control_word = X87FloatingPointUnitControlWord::new(0xFFFF);
+			control_word.set_rounding_control(rounding_control);
+			assert_eq!(control_word.value(), 0xF3FF | bits);
+			assert_eq!(control_word.rounding_control(), rounding_control);
+		}
+	}
+
+	#[test]
+	fn initial_decodes()
+	{
+		let initial = X87FloatingPointUnitControlWord::Initial;
+		assert_eq!(initial.precision_control(), PrecisionControl::DoubleExtendedPrecision);
+		assert_eq!(initial.rounding_control(), RoundingControl::RoundToNearest);
+		assert!(initial.exception_flag_mask_bit_invalid_operation());
+		assert!(initial.exception_flag_mask_bit_precision());
+		assert!(!initial.infinity_control());
+	}
+
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	#[test]
+	fn golden_encodings_are_loaded_and_stored_by_the_processor()
+	{
+		let original = X87FloatingPointUnitControlWord::save();
+
+		let mut control_word = X87FloatingPointUnitControlWord::Initial;
+		control_word.restore();
+		let initial = X87FloatingPointUnitControlWord::save();
+
+		control_word.set_precision_control(PrecisionControl::DoublePrecision);
+		control_word.restore();
+		let double_precision = X87FloatingPointUnitControlWord::save();
+
+		control_word.set_rounding_control(RoundingControl::RoundTowardZero);
+		control_word.restore();
+		let double_precision_toward_zero = X87FloatingPointUnitControlWord::save();
+
+		original.restore();
+
+		assert_eq!(initial.value(), 0x037F);
+		assert_eq!(double_precision.value(), 0x027F);
+		assert_eq!(double_precision.precision_control(), PrecisionControl::DoublePrecision);
+		assert_eq!(double_precision_toward_zero.value(), 0x0E7F);
+		assert_eq!(double_precision_toward_zero.rounding_control(), RoundingControl::RoundTowardZero);
+	}
+
+	fn assert_flag_round_trips(getter: fn(X87FloatingPointUnitControlWord) -> bool, setter: fn(&mut X87FloatingPointUnitControlWord, bool), bit: u16)
+	{
+		let mut control_word = X87FloatingPointUnitControlWord::new(0x0000);
+		setter(&mut control_word, true);
+		assert_eq!(control_word.value(), bit);
+		assert!(getter(control_word));
+
+		let mut control_word = X87FloatingPointUnitControlWord::new(0xFFFF);
+		setter(&mut control_word, false);
+		assert_eq!(control_word.value(), !bit);
+		assert!(!getter(control_word));
+	}
 }
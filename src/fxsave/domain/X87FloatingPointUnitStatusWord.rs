@@ -9,6 +9,22 @@ pub struct X87FloatingPointUnitStatusWord(u16);
 
 impl X87FloatingPointUnitStatusWord
 {
+	/// Creates a new instance.
+	#[inline(always)]
+	pub const fn new(value: u16) -> Self
+	{
+		X87FloatingPointUnitStatusWord(value)
+	}
+
+	/// Raw value.
+	#[inline(always)]
+	pub fn value(self) -> u16
+	{
+		self.0
+	}
+
+	const TopMask: u16 = 0b0011_1000_0000_0000;
+
 	/// Reads the control word after raising any pending unmasked floating point exceptions.
 	///
 	/// Uses the non-`AX` register form of the `FSTSW` instruction (ie always writes to memory); see <https://github.com/HJLebbink/asm-dude/wiki/FSTSW_FNSTSW>.
@@ -82,6 +98,13 @@ impl X87FloatingPointUnitStatusWord
 		self.0 & 0b0000_0000_0000_0001 != 0
 	}
 
+	/// Sets Invalid Operation exception flag, `IE`.
+	#[inline(always)]
+	pub fn set_exception_flag_invalid_operation(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0000_0000_0001, value)
+	}
+
 	/// Denormalized Operand exception flag, `DE`.
 	///
 	/// One of the 6 exception flags that can be controlled by the status word.
@@ -93,6 +116,13 @@ impl X87FloatingPointUnitStatusWord
 		self.0 & 0b0000_0000_0000_0010 != 0
 	}
 
+	/// Sets Denormalized Operand exception flag, `DE`.
+	#[inline(always)]
+	pub fn set_exception_flag_denormalized_operand(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0000_0000_0010, value)
+	}
+
 	/// Zero Divide exception flag, `ZE`.
 	///
 	/// One of the 6 exception flags that can be controlled by the status word.
@@ -104,6 +134,13 @@ impl X87FloatingPointUnitStatusWord
 		self.0 & 0b0000_0000_0000_0100 != 0
 	}
 
+	/// Sets Zero Divide exception flag, `ZE`.
+	#[inline(always)]
+	pub fn set_exception_flag_zero_divide(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0000_0000_0100, value)
+	}
+
 	/// Overflow exception flag, `OE`.
 	///
 	/// One of the 6 exception flags that can be controlled by the status word.
@@ -115,6 +152,13 @@ impl X87FloatingPointUnitStatusWord
 		self.0 & 0b0000_0000_0000_1000 != 0
 	}
 
+	/// Sets Overflow exception flag, `OE`.
+	#[inline(always)]
+	pub fn set_exception_flag_overflow(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0000_0000_1000, value)
+	}
+
 	/// Underflow exception flag, `UE`.
 	///
 	/// One of the 6 exception flags that can be controlled by the status word.
@@ -126,6 +170,13 @@ impl X87FloatingPointUnitStatusWord
 		self.0 & 0b0000_0000_0001_0000 != 0
 	}
 
+	/// Sets Underflow exception flag, `UE`.
+	#[inline(always)]
+	pub fn set_exception_flag_underflow(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0000_0001_0000, value)
+	}
+
 	/// Precision exception flag, `EE`.
 	///
 	/// One of the 6 exception flags that can be controlled by the status word.
@@ -137,6 +188,13 @@ impl X87FloatingPointUnitStatusWord
 		self.0 & 0b0000_0000_0010_0000 != 0
 	}
 
+	/// Sets Precision exception flag, `EE`.
+	#[inline(always)]
+	pub fn set_exception_flag_precision(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0000_0010_0000, value)
+	}
+
 	/// Stack Fault exception flag, `SF`.
 	///
 	/// The stack fault flag indicates that stack overflow or stack underflow has occurred with data in the x87 FPU data register stack.
@@ -150,6 +208,13 @@ impl X87FloatingPointUnitStatusWord
 		self.0 & 0b0000_0000_0100_0000 != 0
 	}
 
+	/// Sets Stack Fault exception flag, `SF`.
+	#[inline(always)]
+	pub fn set_stack_fault(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0000_0100_0000, value)
+	}
+
 	/// Exception Summary Status, `ES`.
 	///
 	/// When set (`true`), a x87 FPU floating-point exception occurred.
@@ -159,6 +224,13 @@ impl X87FloatingPointUnitStatusWord
 		self.0 & 0b0000_0000_1000_0000 != 0
 	}
 
+	/// Sets Exception Summary Status, `ES`.
+	#[inline(always)]
+	pub fn set_exception_summary_status(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0000_1000_0000, value)
+	}
+
 	/// Condition Code `C0`.
 	///
 	/// See Table 8-1 (Condition Code Interpretation) in the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
@@ -168,6 +240,13 @@ impl X87FloatingPointUnitStatusWord
 		self.0 & 0b0000_0001_0000_0000 != 0
 	}
 
+	/// Sets Condition Code `C0`.
+	#[inline(always)]
+	pub fn set_condition_code_c0(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0001_0000_0000, value)
+	}
+
 	/// Condition Code `C1`.
 	///
 	/// See Table 8-1 (Condition Code Interpretation) in the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
@@ -177,6 +256,13 @@ impl X87FloatingPointUnitStatusWord
 		self.0 & 0b0000_0010_0000_0000 != 0
 	}
 
+	/// Sets Condition Code `C1`.
+	#[inline(always)]
+	pub fn set_condition_code_c1(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0010_0000_0000, value)
+	}
+
 	/// Condition Code `C2`.
 	///
 	/// See Table 8-1 (Condition Code Interpretation) in the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
@@ -186,6 +272,13 @@ impl X87FloatingPointUnitStatusWord
 		self.0 & 0b0000_0100_0000_0000 != 0
 	}
 
+	/// Sets Condition Code `C2`.
+	#[inline(always)]
+	pub fn set_condition_code_c2(&mut self, value: bool)
+	{
+		self.set_bits(0b0000_0100_0000_0000, value)
+	}
+
 	/// Condition Code `C3`.
 	///
 	/// See Table 8-1 (Condition Code Interpretation) in the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
@@ -195,6 +288,13 @@ impl X87FloatingPointUnitStatusWord
 		self.0 & 0b0100_0000_0000_0000 != 0
 	}
 
+	/// Sets Condition Code `C3`.
+	#[inline(always)]
+	pub fn set_condition_code_c3(&mut self, value: bool)
+	{
+		self.set_bits(0b0100_0000_0000_0000, value)
+	}
+
 	/// Top of Stack Pointer, `TOP`.
 	///
 	/// A pointer to the x87 FPU data register that is currently at the top of the x87 FPU register stack.
@@ -203,7 +303,18 @@ impl X87FloatingPointUnitStatusWord
 	#[inline(always)]
 	pub fn top(self) -> u8
 	{
-		(self.0 & 0b0011_1000_0000_0000 >> 11) as u8
+		((self.0 & Self::TopMask) >> 11) as u8
+	}
+
+	/// Sets the Top of Stack Pointer, `TOP`.
+	///
+	/// `top` must be less than 8.
+	#[inline(always)]
+	pub fn set_top(&mut self, top: u8)
+	{
+		assert!(top < 8, "top `{}` is not less than 8", top);
+
+		self.0 = (self.0 & !Self::TopMask) | ((top as u16) << 11)
 	}
 
 	/// FPU Busy, `B`, or `B-bit`.
@@ -215,4 +326,89 @@ impl X87FloatingPointUnitStatusWord
 	{
 		self.0 & 0b1000_0000_0000_0000 != 0
 	}
+
+	/// Sets FPU Busy, `B`, or `B-bit`.
+	#[inline(always)]
+	pub fn set_fpu_busy(&mut self, value: bool)
+	{
+		self.set_bits(0b1000_0000_0000_0000, value)
+	}
+
+	#[inline(always)]
+	fn set_bits(&mut self, bits: u16, value: bool)
+	{
+		if value
+		{
+			self.0 |= bits
+		}
+		else
+		{
+			self.0 &= !bits
+		}
+	}
+}
+
+#[cfg(test)]
+mod X87FloatingPointUnitStatusWordTests
+{
+	use super::*;
+
+	#[test]
+	fn flags_round_trip()
+	{
+		use self::X87FloatingPointUnitStatusWord as W;
+
+		assert_flag_round_trips(W::exception_flag_invalid_operation, W::set_exception_flag_invalid_operation, 0x0001);
+		assert_flag_round_trips(W::exception_flag_denormalized_operand, W::set_exception_flag_denormalized_operand, 0x0002);
+		assert_flag_round_trips(W::exception_flag_zero_divide, W::set_exception_flag_zero_divide, 0x0004);
+		assert_flag_round_trips(W::exception_flag_overflow, W::set_exception_flag_overflow, 0x0008);
+		assert_flag_round_trips(W::exception_flag_underflow, W::set_exception_flag_underflow, 0x0010);
+		assert_flag_round_trips(W::exception_flag_precision, W::set_exception_flag_precision, 0x0020);
+		assert_flag_round_trips(W::stack_fault, W::set_stack_fault, 0x0040);
+		assert_flag_round_trips(W::exception_summary_status, W::set_exception_summary_status, 0x0080);
+		assert_flag_round_trips(W::condition_code_c0, W::set_condition_code_c0, 0x0100);
+		assert_flag_round_trips(W::condition_code_c1, W::set_condition_code_c1, 0x0200);
+		assert_flag_round_trips(W::condition_code_c2, W::set_condition_code_c2, 0x0400);
+		assert_flag_round_trips(W::condition_code_c3, W::set_condition_code_c3, 0x4000);
+		assert_flag_round_trips(W::fpu_busy, W::set_fpu_busy, 0x8000);
+	}
+
+	#[test]
+	fn top_round_trips()
+	{
+		for top in 0 .. 8
+		{
+			let bits = (top as u16) << 11;
+
+			let mut status_word = X87FloatingPointUnitStatusWord::new(0x0000);
+			status_word.set_top(top);
+			assert_eq!(status_word.value(), bits);
+			assert_eq!(status_word.top(), top);
+
+			let mut status_word = X87FloatingPointUnitStatusWord::new(0xFFFF);
+			status_word.set_top(top);
+			assert_eq!(status_word.value(), 0xC7FF | bits);
+			assert_eq!(status_word.top(), top);
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn set_top_rejects_out_of_range()
+	{
+		X87FloatingPointUnitStatusWord::default().set_top(8)
+	}
+
+	fn assert_flag_round_trips(getter: fn(X87FloatingPointUnitStatusWord) -> bool, setter: fn(&mut X87FloatingPointUnitStatusWord, bool), bit: u16)
+	{
+		let mut status_word = X87FloatingPointUnitStatusWord::new(0x0000);
+		setter(&mut status_word, true);
+		assert_eq!(status_word.value(), bit);
+		assert!(getter(status_word));
+
+		let mut status_word = X87FloatingPointUnitStatusWord::new(0xFFFF);
+		setter(&mut status_word, false);
+		assert_eq!(status_word.value(), !bit);
+		assert!(!getter(status_word));
+	}
 }
@@ -0,0 +1,218 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// A x87 FPU instruction, as decoded from the x87 FPU opcode register (`FOP`).
+///
+/// Mnemonics are those of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 2, in lower case; 'no-wait' forms are used where both exist, as `FOP` does not record a preceding `FWAIT`.
+///
+/// Undocumented encodings (eg aliases of `FSTP` and `FXCH`) and reserved encodings are not decoded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct X87Instruction
+{
+	/// Mnemonic, eg `fdiv`.
+	pub mnemonic: &'static str,
+
+	/// Operands, eg `st(0), st(3)`.
+	pub operands: X87InstructionOperands,
+}
+
+impl Display for X87Instruction
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		if self.operands == X87InstructionOperands::None
+		{
+			f.write_str(self.mnemonic)
+		}
+		else
+		{
+			write!(f, "{} {}", self.mnemonic, self.operands)
+		}
+	}
+}
+
+impl X87Instruction
+{
+	/// Decodes an instruction from the x87 FPU opcode register (`FOP`).
+	///
+	/// Returns `None` for reserved or undocumented encodings.
+	///
+	/// See Tables A-7 to A-22 (Escape Opcode Instructions) in Appendix A of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 2.
+	#[inline(always)]
+	pub fn decode(fpu_opcode: FloatingPointUnitOpcode) -> Option<Self>
+	{
+		let escape = fpu_opcode.first_opcode_byte() & 0b111;
+		let modrm = fpu_opcode.second_opcode_byte();
+		let reg = (modrm >> 3) & 0b111;
+		let rm = modrm & 0b111;
+
+		if modrm >> 6 != 0b11
+		{
+			Self::decode_memory_form(escape, reg)
+		}
+		else
+		{
+			Self::decode_register_form(escape, reg, rm)
+		}
+	}
+
+	#[inline(always)]
+	fn decode_memory_form(escape: u8, reg: u8) -> Option<Self>
+	{
+		use self::X87MemoryOperand::*;
+
+		const Arithmetic: [&str; 8] = ["fadd", "fmul", "fcom", "fcomp", "fsub", "fsubr", "fdiv", "fdivr"];
+		const IntegerArithmetic: [&str; 8] = ["fiadd", "fimul", "ficom", "ficomp", "fisub", "fisubr", "fidiv", "fidivr"];
+
+		let (mnemonic, memory_operand) = match (escape, reg)
+		{
+			(0b000, _) => (Arithmetic[reg as usize], m32fp),
+
+			(0b001, 0) => ("fld", m32fp),
+			(0b001, 2) => ("fst", m32fp),
+			(0b001, 3) => ("fstp", m32fp),
+			(0b001, 4) => ("fldenv", m14_28byte),
+			(0b001, 5) => ("fldcw", m2byte),
+			(0b001, 6) => ("fnstenv", m14_28byte),
+			(0b001, 7) => ("fnstcw", m2byte),
+
+			(0b010, _) => (IntegerArithmetic[reg as usize], m32int),
+
+			(0b011, 0) => ("fild", m32int),
+			(0b011, 1) => ("fisttp", m32int),
+			(0b011, 2) => ("fist", m32int),
+			(0b011, 3) => ("fistp", m32int),
+			(0b011, 5) => ("fld", m80fp),
+			(0b011, 7) => ("fstp", m80fp),
+
+			(0b100, _) => (Arithmetic[reg as usize], m64fp),
+
+			(0b101, 0) => ("fld", m64fp),
+			(0b101, 1) => ("fisttp", m64int),
+			(0b101, 2) => ("fst", m64fp),
+			(0b101, 3) => ("fstp", m64fp),
+			(0b101, 4) => ("frstor", m94_108byte),
+			(0b101, 6) => ("fnsave", m94_108byte),
+			(0b101, 7) => ("fnstsw", m2byte),
+
+			(0b110, _) => (IntegerArithmetic[reg as usize], m16int),
+
+			(0b111, 0) => ("fild", m16int),
+			(0b111, 1) => ("fisttp", m16int),
+			(0b111, 2) => ("fist", m16int),
+			(0b111, 3) => ("fistp", m16int),
+			(0b111, 4) => ("fbld", m80bcd),
+			(0b111, 5) => ("fild", m64int),
+			(0b111, 6) => ("fbstp", m80bcd),
+			(0b111, 7) => ("fistp", m64int),
+
+			_ => return None,
+		};
+
+		Some
+		(
+			Self
+			{
+				mnemonic,
+				operands: X87InstructionOperands::Memory(memory_operand),
+			}
+		)
+	}
+
+	#[inline(always)]
+	fn decode_register_form(escape: u8, reg: u8, rm: u8) -> Option<Self>
+	{
+		use self::X87InstructionOperands::*;
+
+		const D9Constants: [Option<&str>; 8] = [Some("fld1"), Some("fldl2t"), Some("fldl2e"), Some("fldpi"), Some("fldlg2"), Some("fldln2"), Some("fldz"), Option::None];
+		const D9Transcendentals: [&str; 8] = ["f2xm1", "fyl2x", "fptan", "fpatan", "fxtract", "fprem1", "fdecstp", "fincstp"];
+		const D9Miscellaneous: [&str; 8] = ["fprem", "fyl2xp1", "fsqrt", "fsincos", "frndint", "fscale", "fsin", "fcos"];
+
+		let (mnemonic, operands) = match (escape, reg, rm)
+		{
+			(0b000, 0, i) => ("fadd", St0AndStackRegister(i)),
+			(0b000, 1, i) => ("fmul", St0AndStackRegister(i)),
+			(0b000, 2, i) => ("fcom", StackRegister(i)),
+			(0b000, 3, i) => ("fcomp", StackRegister(i)),
+			(0b000, 4, i) => ("fsub", St0AndStackRegister(i)),
+			(0b000, 5, i) => ("fsubr", St0AndStackRegister(i)),
+			(0b000, 6, i) => ("fdiv", St0AndStackRegister(i)),
+			(0b000, 7, i) => ("fdivr", St0AndStackRegister(i)),
+
+			(0b001, 0, i) => ("fld", StackRegister(i)),
+			(0b001, 1, i) => ("fxch", StackRegister(i)),
+			(0b001, 2, 0) => ("fnop", None),
+			(0b001, 4, 0) => ("fchs", None),
+			(0b001, 4, 1) => ("fabs", None),
+			(0b001, 4, 4) => ("ftst", None),
+			(0b001, 4, 5) => ("fxam", None),
+			(0b001, 5, i) => match D9Constants[i as usize]
+			{
+				Some(mnemonic) => (mnemonic, None),
+				_ => return Option::None,
+			},
+			(0b001, 6, i) => (D9Transcendentals[i as usize], None),
+			(0b001, 7, i) => (D9Miscellaneous[i as usize], None),
+
+			(0b010, 0, i) => ("fcmovb", St0AndStackRegister(i)),
+			(0b010, 1, i) => ("fcmove", St0AndStackRegister(i)),
+			(0b010, 2, i) => ("fcmovbe", St0AndStackRegister(i)),
+			(0b010, 3, i) => ("fcmovu", St0AndStackRegister(i)),
+			(0b010, 5, 1) => ("fucompp", None),
+
+			(0b011, 0, i) => ("fcmovnb", St0AndStackRegister(i)),
+			(0b011, 1, i) => ("fcmovne", St0AndStackRegister(i)),
+			(0b011, 2, i) => ("fcmovnbe", St0AndStackRegister(i)),
+			(0b011, 3, i) => ("fcmovnu", St0AndStackRegister(i)),
+			(0b011, 4, 2) => ("fnclex", None),
+			(0b011, 4, 3) => ("fninit", None),
+			(0b011, 5, i) => ("fucomi", St0AndStackRegister(i)),
+			(0b011, 6, i) => ("fcomi", St0AndStackRegister(i)),
+
+			(0b100, 0, i) => ("fadd", StackRegisterAndSt0(i)),
+			(0b100, 1, i) => ("fmul", StackRegisterAndSt0(i)),
+			(0b100, 4, i) => ("fsubr", StackRegisterAndSt0(i)),
+			(0b100, 5, i) => ("fsub", StackRegisterAndSt0(i)),
+			(0b100, 6, i) => ("fdivr", StackRegisterAndSt0(i)),
+			(0b100, 7, i) => ("fdiv", StackRegisterAndSt0(i)),
+
+			(0b101, 0, i) => ("ffree", StackRegister(i)),
+			(0b101, 2, i) => ("fst", StackRegister(i)),
+			(0b101, 3, i) => ("fstp", StackRegister(i)),
+			(0b101, 4, i) => ("fucom", StackRegister(i)),
+			(0b101, 5, i) => ("fucomp", StackRegister(i)),
+
+			(0b110, 0, i) => ("faddp", StackRegisterAndSt0(i)),
+			(0b110, 1, i) => ("fmulp", StackRegisterAndSt0(i)),
+			(0b110, 3, 1) => ("fcompp", None),
+			(0b110, 4, i) => ("fsubrp", StackRegisterAndSt0(i)),
+			(0b110, 5, i) => ("fsubp", StackRegisterAndSt0(i)),
+			(0b110, 6, i) => ("fdivrp", StackRegisterAndSt0(i)),
+			(0b110, 7, i) => ("fdivp", StackRegisterAndSt0(i)),
+
+			(0b111, 4, 0) => ("fnstsw", AX),
+			(0b111, 5, i) => ("fucomip", St0AndStackRegister(i)),
+			(0b111, 6, i) => ("fcomip", St0AndStackRegister(i)),
+
+			_ => return Option::None,
+		};
+
+		Some
+		(
+			Self
+			{
+				mnemonic,
+				operands,
+			}
+		)
+	}
+
+	/// Does this instruction have a memory operand, whose address is in the x87 FPU data pointer (`FDP`)?
+	#[inline(always)]
+	pub fn has_memory_operand(&self) -> bool
+	{
+		matches!(self.operands, X87InstructionOperands::Memory(_))
+	}
+}
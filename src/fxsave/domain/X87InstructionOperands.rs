@@ -0,0 +1,47 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The operands of a x87 FPU instruction.
+///
+/// Stack register indices are less than 8.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum X87InstructionOperands
+{
+	/// No explicit operands, eg `fchs`.
+	None,
+
+	/// A memory operand, eg `fld m64fp`.
+	Memory(X87MemoryOperand),
+
+	/// The `AX` register, ie `fnstsw ax`.
+	AX,
+
+	/// A stack register `ST(i)`, eg `fld st(1)`.
+	StackRegister(u8),
+
+	/// `ST(0)` as the destination and a stack register `ST(i)` as the source, eg `fdiv st(0), st(3)`.
+	St0AndStackRegister(u8),
+
+	/// A stack register `ST(i)` as the destination and `ST(0)` as the source, eg `faddp st(1), st(0)`.
+	StackRegisterAndSt0(u8),
+}
+
+impl Display for X87InstructionOperands
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::X87InstructionOperands::*;
+
+		match *self
+		{
+			None => Ok(()),
+			Memory(memory_operand) => write!(f, "{}", memory_operand),
+			AX => f.write_str("ax"),
+			StackRegister(i) => write!(f, "st({})", i),
+			St0AndStackRegister(i) => write!(f, "st(0), st({})", i),
+			StackRegisterAndSt0(i) => write!(f, "st({}), st(0)", i),
+		}
+	}
+}
@@ -0,0 +1,64 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The type of the memory operand of a x87 FPU instruction, using the notation of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 2.
+///
+/// The x87 FPU opcode register (`FOP`) does not record any addressing bytes (`SIB` or displacement), so only the type of a memory operand can be recovered from it; its address is in the x87 FPU data pointer (`FDP`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum X87MemoryOperand
+{
+	/// 16-bit integer, `m16int`.
+	m16int,
+
+	/// 32-bit integer, `m32int`.
+	m32int,
+
+	/// 64-bit integer, `m64int`.
+	m64int,
+
+	/// 32-bit single precision floating point value, `m32fp`.
+	m32fp,
+
+	/// 64-bit double precision floating point value, `m64fp`.
+	m64fp,
+
+	/// 80-bit double extended-precision floating point value, `m80fp`.
+	m80fp,
+
+	/// 80-bit packed binary coded decimal (BCD) integer, `m80bcd`.
+	m80bcd,
+
+	/// 2 bytes (a control or status word), `m2byte`.
+	m2byte,
+
+	/// 14 or 28 bytes (a x87 FPU environment), `m14/28byte`.
+	m14_28byte,
+
+	/// 94 or 108 bytes (a x87 FPU state), `m94/108byte`.
+	m94_108byte,
+}
+
+impl Display for X87MemoryOperand
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::X87MemoryOperand::*;
+
+		let notation = match *self
+		{
+			m16int => "m16int",
+			m32int => "m32int",
+			m64int => "m64int",
+			m32fp => "m32fp",
+			m64fp => "m64fp",
+			m80fp => "m80fp",
+			m80bcd => "m80bcd",
+			m2byte => "m2byte",
+			m14_28byte => "m14/28byte",
+			m94_108byte => "m94/108byte",
+		};
+		f.write_str(notation)
+	}
+}
@@ -0,0 +1,283 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// A model of the x87 FPU register stack.
+///
+/// The x87 FPU has eight physical data registers, `R0` to `R7`, which software accesses as a stack, `ST(0)` to `ST(7)`.
+/// `ST(i)` is the physical register `R((TOP + i) mod 8)`, where `TOP` is from the x87 FPU status word.
+///
+/// `FXSAVE`, `XSAVE` and `FSAVE` store the data registers in stack order, but the tag word (abridged or not) is indexed by physical register; this model reconciles the two.
+///
+/// See Section 8.1.2 (x87 FPU Data Registers) of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct X87RegisterStack
+{
+	top: u8,
+
+	/// Indexed by physical register.
+	physical_registers: [FloatingPointOrMMRegisterValue; 8],
+
+	/// Bit `j` is set if physical register `Rj` is not empty.
+	abridged_fpu_tag_word: AbridgedFpuTagWord,
+}
+
+impl X87RegisterStack
+{
+	/// Creates a new instance from saved `x87` state.
+	#[inline(always)]
+	pub fn new(x87_state_part_1: &X87StatePart1, x87_state_part_2: &X87StatePart2) -> Self
+	{
+		let top = x87_state_part_1.fpu_status_word.top();
+
+		let mut physical_registers: [FloatingPointOrMMRegisterValue; 8] = Default::default();
+		for i in 0 .. 8
+		{
+			physical_registers[Self::physical_register_index_for(top, i) as usize] = x87_state_part_2.st(i).clone();
+		}
+
+		Self
+		{
+			top,
+			physical_registers,
+			abridged_fpu_tag_word: x87_state_part_1.abridged_fpu_tag_word,
+		}
+	}
+
+	/// Writes `TOP`, the abridged tag word and the data registers into `x87` state.
+	///
+	/// Other fields are left unchanged.
+	#[inline(always)]
+	pub fn write(&self, x87_state_part_1: &mut X87StatePart1, x87_state_part_2: &mut X87StatePart2)
+	{
+		x87_state_part_1.fpu_status_word.set_top(self.top);
+		x87_state_part_1.abridged_fpu_tag_word = self.abridged_fpu_tag_word;
+		for i in 0 .. 8
+		{
+			*x87_state_part_2.st_mut(i) = self.physical_registers[self.physical_register_index(i) as usize].clone();
+		}
+	}
+
+	/// Top of Stack Pointer, `TOP`.
+	#[inline(always)]
+	pub fn top(&self) -> u8
+	{
+		self.top
+	}
+
+	/// Abridged tag word, as saved by `FXSAVE`.
+	#[inline(always)]
+	pub fn abridged_fpu_tag_word(&self) -> AbridgedFpuTagWord
+	{
+		self.abridged_fpu_tag_word
+	}
+
+	/// Index `j` of the physical register `Rj` that is `ST(i)`.
+	///
+	/// `i` must be less than 8.
+	#[inline(always)]
+	pub fn physical_register_index(&self, i: u8) -> u8
+	{
+		Self::physical_register_index_for(self.top, i)
+	}
+
+	/// Index `i` of the stack register `ST(i)` that is the physical register `Rj`.
+	///
+	/// `j` must be less than 8.
+	#[inline(always)]
+	pub fn stack_register_index(&self, j: u8) -> u8
+	{
+		debug_assert!(j < 8, "j `{}` is not less than 8", j);
+
+		(j + 8 - self.top) & 0b111
+	}
+
+	/// Is the physical register `Rj` empty?
+	///
+	/// `j` must be less than 8.
+	#[inline(always)]
+	pub fn physical_register_is_empty(&self, j: u8) -> bool
+	{
+		self.abridged_fpu_tag_word.fpu_data_register_STj_has_an_empty_tag(j)
+	}
+
+	/// Is `ST(i)` empty?
+	///
+	/// `i` must be less than 8.
+	#[inline(always)]
+	pub fn st_is_empty(&self, i: u8) -> bool
+	{
+		self.physical_register_is_empty(self.physical_register_index(i))
+	}
+
+	/// Value of `ST(i)`, or `None` if empty.
+	///
+	/// `i` must be less than 8.
+	#[inline(always)]
+	pub fn st(&self, i: u8) -> Option<&FloatingPointOrMMRegisterValue>
+	{
+		self.physical_register(self.physical_register_index(i))
+	}
+
+	/// Value of the physical register `Rj`, or `None` if empty.
+	///
+	/// `j` must be less than 8.
+	#[inline(always)]
+	pub fn physical_register(&self, j: u8) -> Option<&FloatingPointOrMMRegisterValue>
+	{
+		if self.physical_register_is_empty(j)
+		{
+			None
+		}
+		else
+		{
+			Some(&self.physical_registers[j as usize])
+		}
+	}
+
+	/// Tag of the physical register `Rj`, computed from its value if not empty.
+	///
+	/// `j` must be less than 8.
+	#[inline(always)]
+	pub fn tag(&self, j: u8) -> X87Tag
+	{
+		match self.physical_register(j)
+		{
+			None => X87Tag::Empty,
+			Some(value) => X87Tag::for_value(value.extended_precision_floating_point_value()),
+		}
+	}
+
+	/// The full x87 FPU tag word, as saved by `FSAVE` and `FSTENV`.
+	///
+	/// Bits `2j + 1` and `2j` are the tag of physical register `Rj`.
+	#[inline(always)]
+	pub fn fpu_tag_word(&self) -> u16
+	{
+		(0 .. 8).fold(0, |fpu_tag_word, j| fpu_tag_word | ((self.tag(j) as u16) << (j * 2)))
+	}
+
+	/// Sets the physical register emptiness from a full x87 FPU tag word, as restored by `FRSTOR` and `FLDENV`.
+	///
+	/// Only whether a tag is empty is significant; the x87 FPU recomputes the other tags from the register values.
+	#[inline(always)]
+	pub fn set_fpu_tag_word(&mut self, fpu_tag_word: u16)
+	{
+		let abridged = (0 .. 8).fold(0, |abridged, j| if (fpu_tag_word >> (j * 2)) & 0b11 == X87Tag::Empty as u16
+		{
+			abridged
+		}
+		else
+		{
+			abridged | (1 << j)
+		});
+		self.abridged_fpu_tag_word = AbridgedFpuTagWord::new(abridged)
+	}
+
+	/// Pushes `value` on to the stack, as `FLD` does: `TOP` is decremented and `value` becomes `ST(0)`.
+	///
+	/// Returns `false`, changing nothing, on stack overflow (the new `ST(0)` is not empty).
+	#[inline(always)]
+	pub fn push(&mut self, value: FloatingPointOrMMRegisterValue) -> bool
+	{
+		let new_top = (self.top + 7) & 0b111;
+		if !self.physical_register_is_empty(new_top)
+		{
+			return false
+		}
+
+		self.top = new_top;
+		self.set_physical_register(new_top, Some(value));
+		true
+	}
+
+	/// Pops `ST(0)` off the stack, as `FSTP` does: `ST(0)` is marked empty and `TOP` is incremented.
+	///
+	/// Returns `None`, changing nothing, on stack underflow (`ST(0)` is empty).
+	#[inline(always)]
+	pub fn pop(&mut self) -> Option<FloatingPointOrMMRegisterValue>
+	{
+		let j = self.top;
+		if self.physical_register_is_empty(j)
+		{
+			return None
+		}
+
+		let value = self.physical_registers[j as usize].clone();
+		self.set_physical_register(j, None);
+		self.top = (self.top + 1) & 0b111;
+		Some(value)
+	}
+
+	/// Exchanges `ST(0)` and `ST(i)`, as `FXCH` does.
+	///
+	/// Returns `false`, changing nothing, on stack underflow (either register is empty).
+	///
+	/// `i` must be less than 8.
+	#[inline(always)]
+	pub fn exchange(&mut self, i: u8) -> bool
+	{
+		let st0 = self.physical_register_index(0);
+		let sti = self.physical_register_index(i);
+		if self.physical_register_is_empty(st0) || self.physical_register_is_empty(sti)
+		{
+			return false
+		}
+
+		self.physical_registers.swap(st0 as usize, sti as usize);
+		true
+	}
+
+	/// Sets `ST(i)`, or marks it empty if `value` is `None` (as `FFREE` does); `TOP` is unchanged.
+	///
+	/// `i` must be less than 8.
+	#[inline(always)]
+	pub fn set_st(&mut self, i: u8, value: Option<FloatingPointOrMMRegisterValue>)
+	{
+		let j = self.physical_register_index(i);
+		self.set_physical_register(j, value)
+	}
+
+	/// Increments `TOP`, as `FINCSTP` does; tags and registers are unchanged.
+	#[inline(always)]
+	pub fn increment_top(&mut self)
+	{
+		self.top = (self.top + 1) & 0b111
+	}
+
+	/// Decrements `TOP`, as `FDECSTP` does; tags and registers are unchanged.
+	#[inline(always)]
+	pub fn decrement_top(&mut self)
+	{
+		self.top = (self.top + 7) & 0b111
+	}
+
+	#[inline(always)]
+	fn set_physical_register(&mut self, j: u8, value: Option<FloatingPointOrMMRegisterValue>)
+	{
+		let abridged = self.abridged_fpu_tag_word.value();
+		let abridged = match value
+		{
+			None =>
+			{
+				self.physical_registers[j as usize] = FloatingPointOrMMRegisterValue::default();
+				abridged & !(1 << j)
+			}
+
+			Some(value) =>
+			{
+				self.physical_registers[j as usize] = value;
+				abridged | (1 << j)
+			}
+		};
+		self.abridged_fpu_tag_word = AbridgedFpuTagWord::new(abridged)
+	}
+
+	#[inline(always)]
+	fn physical_register_index_for(top: u8, i: u8) -> u8
+	{
+		debug_assert!(i < 8, "i `{}` is not less than 8", i);
+
+		(top + i) & 0b111
+	}
+}
@@ -0,0 +1,48 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The variant of instruction that saved `x87` state.
+///
+/// This determines how the x87 FPU instruction pointer (`FIP`) and data pointer (`FDP`) were saved; see `X87StatePart1::fpu_pointers()`.
+///
+/// See Section 10.5.1 (FXSAVE Area) and Section 13.5.1 (x87 State) of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum X87SaveVariant
+{
+	/// `FXSAVE` outside of 64-bit mode, or in 64-bit mode without `REX.W`.
+	///
+	/// Saves 32-bit offsets and 16-bit selectors.
+	FXSAVE,
+
+	/// `FXSAVE64`, ie `FXSAVE` in 64-bit mode with `REX.W`.
+	///
+	/// Saves 64-bit offsets and no selectors.
+	FXSAVE64,
+
+	/// `XSAVE`, `XSAVEOPT`, `XSAVEC` or `XSAVES` outside of 64-bit mode, or in 64-bit mode without `REX.W`.
+	///
+	/// Saves 32-bit offsets and 16-bit selectors.
+	XSAVE,
+
+	/// `XSAVE64`, `XSAVEOPT64`, `XSAVEC64` or `XSAVES64`, ie in 64-bit mode with `REX.W`.
+	///
+	/// Saves 64-bit offsets and no selectors.
+	XSAVE64,
+}
+
+impl X87SaveVariant
+{
+	/// Were 64-bit offsets, without selectors, saved?
+	#[inline(always)]
+	pub fn saves_64_bit_offsets(self) -> bool
+	{
+		use self::X87SaveVariant::*;
+
+		match self
+		{
+			FXSAVE | XSAVE => false,
+			FXSAVE64 | XSAVE64 => true,
+		}
+	}
+}
@@ -0,0 +1,41 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// The tag of a x87 FPU data register, as stored in the (unabridged) x87 FPU tag word by `FSAVE` and `FSTENV`.
+///
+/// See Section 8.1.7 and Figure 8-7 of the Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[repr(u8)]
+pub enum X87Tag
+{
+	/// Valid (a normal finite value).
+	Valid = 0b00,
+
+	/// Zero.
+	Zero = 0b01,
+
+	/// Special: invalid (NaN or unsupported), infinity or denormal.
+	Special = 0b10,
+
+	/// Empty.
+	Empty = 0b11,
+}
+
+impl X87Tag
+{
+	/// Tag of a non-empty register containing `value`, as computed by the x87 FPU.
+	#[inline(always)]
+	pub fn for_value(value: ExtendedPrecisionFloatingPointValue) -> Self
+	{
+		use self::ExtendedPrecisionFloatingPointClass::*;
+		use self::X87Tag::*;
+
+		match value.class()
+		{
+			Normal => Valid,
+			ExtendedPrecisionFloatingPointClass::Zero => X87Tag::Zero,
+			_ => Special,
+		}
+	}
+}
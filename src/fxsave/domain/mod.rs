@@ -6,11 +6,22 @@ use super::*;
 
 
 include!("AbridgedFpuTagWord.rs");
+include!("ExactDecimal.rs");
+include!("ExtendedPrecisionFloatingPointClass.rs");
+include!("ExtendedPrecisionFloatingPointValue.rs");
 include!("FloatingPointOrMMRegisterValue.rs");
 include!("FloatingPointUnitOpcode.rs");
+include!("FpuPointers.rs");
 include!("PrecisionControl.rs");
+include!("X87FaultReport.rs");
 include!("X87FloatingPointUnitControlWord.rs");
 include!("X87FloatingPointUnitStatusWord.rs");
+include!("X87Instruction.rs");
+include!("X87InstructionOperands.rs");
+include!("X87MemoryOperand.rs");
+include!("X87RegisterStack.rs");
+include!("X87SaveVariant.rs");
+include!("X87Tag.rs");
 
 
 /// Floating Point Unit (FPU) data point offset.
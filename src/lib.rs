@@ -9,6 +9,7 @@
 #![deny(unreachable_patterns)]
 #![feature(allocator_api)]
 #![feature(asm)]
+#![feature(const_fn_union)]
 #![feature(extern_types)]
 #![feature(stdsimd)]
 
@@ -21,6 +22,19 @@
 #[macro_use] extern crate arrayref;
 
 
+use self::extended_state_components::advanced_matrix_extensions::*;
+use self::extended_state_components::advanced_performance_extensions::*;
+use self::extended_state_components::amd_lightweight_profiling::*;
+use self::extended_state_components::architectural_last_branch_records::*;
+use self::extended_state_components::avx_512::*;
+use self::extended_state_components::control_flow_enforcement_technology::*;
+use self::extended_state_components::hardware_duty_cycling::*;
+use self::extended_state_components::hardware_p_states::*;
+use self::extended_state_components::memory_protection_extensions::*;
+use self::extended_state_components::process_address_space_identifier::*;
+use self::extended_state_components::processor_trace::*;
+use self::extended_state_components::protection_keys::*;
+use self::extended_state_components::user_interrupts::*;
 use self::state_component_bitmaps::*;
 use self::state_components::*;
 use self::fxsave::*;
@@ -29,6 +43,15 @@ use self::fxsave::domain::floating_point_unit_data_pointer_offset::*;
 use self::fxsave::domain::floating_point_unit_instruction_pointer_offset::*;
 
 #[cfg(target_arch = "x86")] use ::std::arch::x86::__cpuid;
+#[cfg(target_arch = "x86")] use ::std::arch::x86::__m128;
+#[cfg(target_arch = "x86")] use ::std::arch::x86::__m128d;
+#[cfg(target_arch = "x86")] use ::std::arch::x86::__m128i;
+#[cfg(target_arch = "x86")] use ::std::arch::x86::__m256;
+#[cfg(target_arch = "x86")] use ::std::arch::x86::__m256d;
+#[cfg(target_arch = "x86")] use ::std::arch::x86::__m256i;
+#[cfg(target_arch = "x86")] use ::std::arch::x86::__m512;
+#[cfg(target_arch = "x86")] use ::std::arch::x86::__m512d;
+#[cfg(target_arch = "x86")] use ::std::arch::x86::__m512i;
 #[cfg(target_arch = "x86")] use ::std::arch::x86::__cpuid_count;
 #[cfg(all(target_arch = "x86", target_feature = "sse"))] use ::std::arch::x86::_mm_getcsr;
 #[cfg(all(target_arch = "x86", target_feature = "sse"))] use ::std::arch::x86::_mm_setcsr;
@@ -37,36 +60,59 @@ use self::fxsave::domain::floating_point_unit_instruction_pointer_offset::*;
 #[cfg(all(target_arch = "x86", target_feature = "xsave"))] use ::std::arch::x86::_XCR_XFEATURE_ENABLED_MASK;
 #[cfg(all(target_arch = "x86", target_feature = "xsave"))] use ::std::arch::x86::_xgetbv;
 #[cfg(all(target_arch = "x86", target_feature = "xsave"))] use ::std::arch::x86::_xrstor;
+#[cfg(all(target_arch = "x86", target_feature = "xsave", target_feature = "xsaves"))] use ::std::arch::x86::_xrstors;
 #[cfg(all(target_arch = "x86", target_feature = "xsave"))] use ::std::arch::x86::_xsave;
 #[cfg(all(target_arch = "x86", target_feature = "xsave", target_feature = "xsavec"))] use ::std::arch::x86::_xsavec;
 #[cfg(all(target_arch = "x86", target_feature = "xsave", target_feature = "xsaveopt"))] use ::std::arch::x86::_xsaveopt;
+#[cfg(all(target_arch = "x86", target_feature = "xsave", target_feature = "xsaves"))] use ::std::arch::x86::_xsaves;
 #[cfg(all(target_arch = "x86", target_feature = "xsave"))] use ::std::arch::x86::_xsetbv;
 #[cfg(target_arch = "x86")] use ::std::arch::x86::CpuidResult;
 #[cfg(target_arch = "x86")] use ::std::arch::x86::has_cpuid;
 #[cfg(target_arch = "x86_64")] use ::std::arch::x86_64::__cpuid;
+#[cfg(target_arch = "x86_64")] use ::std::arch::x86_64::__m128;
+#[cfg(target_arch = "x86_64")] use ::std::arch::x86_64::__m128d;
+#[cfg(target_arch = "x86_64")] use ::std::arch::x86_64::__m128i;
+#[cfg(target_arch = "x86_64")] use ::std::arch::x86_64::__m256;
+#[cfg(target_arch = "x86_64")] use ::std::arch::x86_64::__m256d;
+#[cfg(target_arch = "x86_64")] use ::std::arch::x86_64::__m256i;
+#[cfg(target_arch = "x86_64")] use ::std::arch::x86_64::__m512;
+#[cfg(target_arch = "x86_64")] use ::std::arch::x86_64::__m512d;
+#[cfg(target_arch = "x86_64")] use ::std::arch::x86_64::__m512i;
 #[cfg(target_arch = "x86_64")] use ::std::arch::x86_64::__cpuid_count;
 #[cfg(all(target_arch = "x86_64", target_feature = "sse"))] use ::std::arch::x86_64::_mm_getcsr;
 #[cfg(all(target_arch = "x86_64", target_feature = "sse"))] use ::std::arch::x86_64::_mm_setcsr;
+#[cfg(all(target_arch = "x86_64", target_feature = "fxsr"))] use ::std::arch::x86_64::_fxrstor;
 #[cfg(all(target_arch = "x86_64", target_feature = "fxsr"))] use ::std::arch::x86_64::_fxrstor64;
+#[cfg(all(target_arch = "x86_64", target_feature = "fxsr"))] use ::std::arch::x86_64::_fxsave;
 #[cfg(all(target_arch = "x86_64", target_feature = "fxsr"))] use ::std::arch::x86_64::_fxsave64;
 #[cfg(all(target_arch = "x86_64", target_feature = "xsave"))] use ::std::arch::x86_64::_XCR_XFEATURE_ENABLED_MASK;
 #[cfg(all(target_arch = "x86_64", target_feature = "xsave"))] use ::std::arch::x86_64::_xgetbv;
+#[cfg(all(target_arch = "x86_64", target_feature = "xsave"))] use ::std::arch::x86_64::_xrstor;
 #[cfg(all(target_arch = "x86_64", target_feature = "xsave"))] use ::std::arch::x86_64::_xrstor64;
+#[cfg(all(target_arch = "x86_64", target_feature = "xsave", target_feature = "xsaves"))] use ::std::arch::x86_64::_xrstors64;
+#[cfg(all(target_arch = "x86_64", target_feature = "xsave"))] use ::std::arch::x86_64::_xsave;
 #[cfg(all(target_arch = "x86_64", target_feature = "xsave"))] use ::std::arch::x86_64::_xsave64;
 #[cfg(all(target_arch = "x86_64", target_feature = "xsave", target_feature = "xsavec"))] use ::std::arch::x86_64::_xsavec64;
 #[cfg(all(target_arch = "x86_64", target_feature = "xsave", target_feature = "xsaveopt"))] use ::std::arch::x86_64::_xsaveopt64;
+#[cfg(all(target_arch = "x86_64", target_feature = "xsave", target_feature = "xsaves"))] use ::std::arch::x86_64::_xsaves64;
 #[cfg(all(target_arch = "x86_64", target_feature = "xsave"))] use ::std::arch::x86_64::_xsetbv;
 #[cfg(target_arch = "x86_64")] use ::std::arch::x86_64::CpuidResult;
 #[cfg(target_arch = "x86_64")] use ::std::arch::x86_64::has_cpuid;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "xsave"))] use ::std::alloc::AllocErr;
 use ::std::alloc::Alloc;
 use ::std::alloc::Layout;
+use ::std::cmp::max;
+use ::std::cmp::min;
 use ::std::cmp::Ordering;
+use ::std::convert::TryFrom;
 use ::std::fmt;
 use ::std::fmt::Debug;
+use ::std::fmt::Display;
 use ::std::fmt::Formatter;
 use ::std::hash::Hash;
 use ::std::hash::Hasher;
+use ::std::marker::PhantomData;
+use ::std::mem::size_of;
 use ::std::mem::transmute;
 use ::std::mem::uninitialized;
 use ::std::mem::zeroed;
@@ -74,12 +120,21 @@ use ::std::ops::Deref;
 use ::std::ops::DerefMut;
 use ::std::ptr::NonNull;
 use ::std::slice::from_raw_parts;
+use ::std::slice::from_raw_parts_mut;
 
 // target_env sgx
 
 
+include!("Avx512ZmmRegisterValue.rs");
+include!("AvxYmmRegisterValue.rs");
+include!("BFloat16.rs");
+include!("CompatibilityModeState.rs");
 include!("ExtendedStateInformation.rs");
+include!("Float16.rs");
 include!("MxcsrRegisterValue.rs");
+include!("PrivilegedOperations.rs");
+include!("ProcessorVendor.rs");
+include!("Ring0PrivilegedOperations.rs");
 include!("RoundingControl.rs");
 include!("SseXmmRegisterValue.rs");
 include!("XSaveArea.rs");
@@ -88,6 +143,10 @@ include!("XSaveExtendedRegion.rs");
 include!("XSaveHeader.rs");
 
 
+/// Typed views of extended state components.
+pub mod extended_state_components;
+
+
 /// State component bitmaps.
 pub mod state_component_bitmaps;
 
@@ -96,10 +155,9 @@ pub mod state_component_bitmaps;
 pub mod state_components;
 
 
+/// Legacy x87 state saving using `FSAVE` and `FSTENV`.
+pub mod fsave;
+
+
 /// Legacy x87 and `SSE` state saving using `FXSAVE`.
 pub mod fxsave;
-
-// TODO: Load / store x87 control (?and status)? word
-//
-// FLDCW: Load x87 FPU control word from memory.
-// FSTSW/FNSTSW: Store x87 FPU status word to memory or another register.
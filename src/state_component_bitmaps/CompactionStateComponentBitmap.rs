@@ -46,10 +46,4 @@ impl CompactionStateComponentBitmap
 	{
 		self.state_component_bitmap().bit_set(i)
 	}
-
-	#[inline(always)]
-	pub(crate) fn bits_are_zero(self, mask: u64) -> bool
-	{
-		self.state_component_bitmap().bits_are_zero(mask)
-	}
 }
@@ -11,6 +11,14 @@ pub struct StateComponentBitmap(pub(crate) u64);
 
 impl StateComponentBitmap
 {
+	/// The pair of Intel® Advanced Matrix Extensions (`AMX`) state components, `XTILECFG` and `XTILEDATA`.
+	///
+	/// `XCR0` requires that both of these are enabled or both are disabled; `XSETBV` raises a general-protection fault (`#GP`) otherwise.
+	pub const AMX: Self = Self((1 << 17) | (1 << 18));
+
+	/// Address of the `IA32_XSS` model-specific register (`MSR`).
+	const IA32_XSS: u32 = 0x0DA0;
+
 	/// Read the value of the register `XCR0`.
 	///
 	/// Will only work if the Operating System has set bit 18 in the register `CR4.OSXSAVE`, otherwise usage will cause an invalid-opcode exception (`#UD`).
@@ -43,6 +51,30 @@ impl StateComponentBitmap
 		unsafe { _xsetbv(_XCR_XFEATURE_ENABLED_MASK, self.0) }
 	}
 
+	/// Read the value of the `IA32_XSS` `MSR`, which enables supervisor state components for `XSAVES` and `XRSTORS`.
+	///
+	/// # Safety
+	///
+	/// The processor must support the `IA32_XSS` `MSR` (see `ExtendedStateInformation.has_xsaves_feature`).
+	#[inline(always)]
+	pub unsafe fn save_from_ia32_xss(privileged_operations: &impl PrivilegedOperations) -> Self
+	{
+		Self(privileged_operations.read_model_specific_register(Self::IA32_XSS))
+	}
+
+	/// Write this value into the `IA32_XSS` `MSR`, which enables supervisor state components for `XSAVES` and `XRSTORS`.
+	///
+	/// Afterwards, a new `ExtendedStateInformation` should be created, as `xsaves_area_size_enabled_features` depends on this value.
+	///
+	/// # Safety
+	///
+	/// The processor must support the `IA32_XSS` `MSR` (see `ExtendedStateInformation.has_xsaves_feature`) and every state component present must be a supervisor state component supported by the processor (see `ExtendedStateInformation::supervisor_state_component_possible()`); otherwise a general-protection fault (`#GP`) occurs.
+	#[inline(always)]
+	pub unsafe fn restore_to_ia32_xss(self, privileged_operations: &impl PrivilegedOperations)
+	{
+		privileged_operations.write_model_specific_register(Self::IA32_XSS, self.0)
+	}
+
 	/// Is present?
 	#[inline(always)]
 	pub fn set_is_present(&mut self, state_component: StateComponent)
@@ -59,15 +91,16 @@ impl StateComponentBitmap
 		self.bit_set(state_component.bit())
 	}
 
+	/// Are all state components in `other` also present?
 	#[inline(always)]
-	fn bit_set(self, bit: u8) -> bool
+	pub fn contains(self, other: Self) -> bool
 	{
-		self.0 & (1 << (bit as u64)) != 0
+		self.0 & other.0 == other.0
 	}
 
 	#[inline(always)]
-	fn bits_are_zero(self, mask: u64) -> bool
+	pub(crate) fn bit_set(self, bit: u8) -> bool
 	{
-		(self.0 & mask) == 0
+		self.0 & (1 << (bit as u64)) != 0
 	}
 }
@@ -34,9 +34,6 @@ impl NotSizingQueryableUserStateComponent
 	#[inline(always)]
 	pub const fn to_state_component(self) -> StateComponent
 	{
-		StateComponent
-		{
-			user: Self::to_user_state_component(self)
-		}
+		StateComponent(self as u8)
 	}
 }
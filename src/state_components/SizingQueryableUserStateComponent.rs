@@ -33,6 +33,26 @@ pub enum SizingQueryableUserStateComponent
 	///
 	/// See Section 13.5.7 of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture).
 	PKRU = 9,
+
+	/// The state component used for the Intel® Advanced Matrix Extensions (`AMX` state) 64-byte tile configuration register `TILECFG` (`XTILECFG` state).
+	///
+	/// Must be enabled in `XCR0` together with `XTILEDATA`.
+	XTILECFG = 17,
+
+	/// The state component used for the Intel® Advanced Matrix Extensions (`AMX` state) eight 1KB tile registers `TMM0`–`TMM7` (`XTILEDATA` state).
+	///
+	/// Must be enabled in `XCR0` together with `XTILECFG`.
+	XTILEDATA = 18,
+
+	/// The state component used for the Intel® Advanced Performance Extensions (`APX` state) sixteen 64-bit extended general purpose registers `R16`–`R31` (`EGPR` state).
+	///
+	/// In the standard format of a `XSAVE` area this occupies the same bytes as the `BNDREGS_MPX` and `BNDCSR_MPX` state components.
+	EGPR_APX = 19,
+
+	/// The state component used for AMD Lightweight Profiling (`LWP` state).
+	///
+	/// Only present on AMD processors.
+	LWP = 62,
 }
 
 impl SizingQueryableUserStateComponent
@@ -51,10 +71,7 @@ impl SizingQueryableUserStateComponent
 	#[inline(always)]
 	pub const fn to_state_component(self) -> StateComponent
 	{
-		StateComponent
-		{
-			user: Self::to_user_state_component(self)
-		}
+		StateComponent(self as u8)
 	}
 
 	/// Query size using `CPUID` of this user state component.
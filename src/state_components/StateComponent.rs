@@ -2,52 +2,147 @@
 // Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
 
 
-/// An state component.
-#[derive(Copy, Clone)]
-pub union StateComponent
+/// A state component.
+///
+/// Can hold any architectural state component bit, from 0 to 62 inclusive, including those which this crate does not know about; bit 63 of a state component bitmap is never a state component.
+///
+/// Use `kind()` to find out if this is a state component known to this crate.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct StateComponent(u8);
+
+impl TryFrom<u8> for StateComponent
 {
-	/// An user state component.
-	pub user: UserStateComponent,
+	type Error = u8;
+
+	/// Fails, returning `bit`, if `bit` is greater than 62.
+	#[inline(always)]
+	fn try_from(bit: u8) -> Result<Self, Self::Error>
+	{
+		if bit <= Self::MaximumBit
+		{
+			Ok(Self(bit))
+		}
+		else
+		{
+			Err(bit)
+		}
+	}
+}
 
-	/// A supervisor state component.
-	pub supervisor: SupervisorStateComponent,
+impl From<StateComponent> for u8
+{
+	#[inline(always)]
+	fn from(value: StateComponent) -> Self
+	{
+		value.bit()
+	}
+}
+
+impl From<NotSizingQueryableUserStateComponent> for StateComponent
+{
+	#[inline(always)]
+	fn from(value: NotSizingQueryableUserStateComponent) -> Self
+	{
+		value.to_state_component()
+	}
+}
+
+impl From<SizingQueryableUserStateComponent> for StateComponent
+{
+	#[inline(always)]
+	fn from(value: SizingQueryableUserStateComponent) -> Self
+	{
+		value.to_state_component()
+	}
+}
+
+impl From<SupervisorStateComponent> for StateComponent
+{
+	#[inline(always)]
+	fn from(value: SupervisorStateComponent) -> Self
+	{
+		value.to_state_component()
+	}
+}
+
+impl From<UserStateComponent> for StateComponent
+{
+	#[inline(always)]
+	fn from(value: UserStateComponent) -> Self
+	{
+		value.to_state_component()
+	}
 }
 
 impl StateComponent
 {
+	/// Maximum state component bit, inclusive.
+	pub const MaximumBit: u8 = 62;
+
 	/// `x87` user state component.
-	pub const x87: StateComponent = UserStateComponent::x87.to_state_component();
+	pub const x87: StateComponent = NotSizingQueryableUserStateComponent::x87.to_state_component();
 
 	/// `SSE` user state component.
-	pub const SSE: StateComponent = UserStateComponent::SSE.to_state_component();
+	pub const SSE: StateComponent = NotSizingQueryableUserStateComponent::SSE.to_state_component();
 
 	/// `AVX` user state component.
-	pub const AVX: StateComponent = UserStateComponent::AVX.to_state_component();
+	pub const AVX: StateComponent = SizingQueryableUserStateComponent::AVX.to_state_component();
 
 	/// `BNDREGS_MPX` user state component.
-	pub const BNDREGS_MPX: StateComponent = UserStateComponent::BNDREGS_MPX.to_state_component();
+	pub const BNDREGS_MPX: StateComponent = SizingQueryableUserStateComponent::BNDREGS_MPX.to_state_component();
 
 	/// `BNDCSR_MPX` user state component.
-	pub const BNDCSR_MPX: StateComponent = UserStateComponent::BNDCSR_MPX.to_state_component();
+	pub const BNDCSR_MPX: StateComponent = SizingQueryableUserStateComponent::BNDCSR_MPX.to_state_component();
 
 	/// `opmask_AVX_512` user state component.
-	pub const opmask_AVX_512: StateComponent = UserStateComponent::opmask_AVX_512.to_state_component();
+	pub const opmask_AVX_512: StateComponent = SizingQueryableUserStateComponent::opmask_AVX_512.to_state_component();
 
 	/// `ZMM_Hi256_AVX_512` user state component.
-	pub const ZMM_Hi256_AVX_512: StateComponent = UserStateComponent::ZMM_Hi256_AVX_512.to_state_component();
+	pub const ZMM_Hi256_AVX_512: StateComponent = SizingQueryableUserStateComponent::ZMM_Hi256_AVX_512.to_state_component();
 
 	/// `Hi16_ZMM_AVX_512` user state component.
-	pub const Hi16_ZMM_AVX_512: StateComponent = UserStateComponent::Hi16_ZMM_AVX_512.to_state_component();
+	pub const Hi16_ZMM_AVX_512: StateComponent = SizingQueryableUserStateComponent::Hi16_ZMM_AVX_512.to_state_component();
 
 	/// `PKRU` user state component.
-	pub const PKRU: StateComponent = UserStateComponent::PKRU.to_state_component();
+	pub const PKRU: StateComponent = SizingQueryableUserStateComponent::PKRU.to_state_component();
+
+	/// `XTILECFG` user state component.
+	pub const XTILECFG: StateComponent = SizingQueryableUserStateComponent::XTILECFG.to_state_component();
+
+	/// `XTILEDATA` user state component.
+	pub const XTILEDATA: StateComponent = SizingQueryableUserStateComponent::XTILEDATA.to_state_component();
+
+	/// `EGPR_APX` user state component.
+	pub const EGPR_APX: StateComponent = SizingQueryableUserStateComponent::EGPR_APX.to_state_component();
+
+	/// `LWP` user state component.
+	pub const LWP: StateComponent = SizingQueryableUserStateComponent::LWP.to_state_component();
 
 	/// `PT` supervisor state component.
 	pub const PT: StateComponent = SupervisorStateComponent::PT.to_state_component();
 
+	/// `PASID` supervisor state component.
+	pub const PASID: StateComponent = SupervisorStateComponent::PASID.to_state_component();
+
+	/// `CET_U` supervisor state component.
+	pub const CET_U: StateComponent = SupervisorStateComponent::CET_U.to_state_component();
+
+	/// `CET_S` supervisor state component.
+	pub const CET_S: StateComponent = SupervisorStateComponent::CET_S.to_state_component();
+
 	/// `HDC` supervisor state component.
 	pub const HDC: StateComponent = SupervisorStateComponent::HDC.to_state_component();
 
+	/// `UINTR` supervisor state component.
+	pub const UINTR: StateComponent = SupervisorStateComponent::UINTR.to_state_component();
+
+	/// `LBR` supervisor state component.
+	pub const LBR: StateComponent = SupervisorStateComponent::LBR.to_state_component();
+
+	/// `HWP` supervisor state component.
+	pub const HWP: StateComponent = SupervisorStateComponent::HWP.to_state_component();
+
 	/// Convenience function to construct a state component.
 	#[inline(always)]
 	pub const fn not_sizing_queryable_user_state_component(not_sizing_queryable_user_state_component: NotSizingQueryableUserStateComponent) -> StateComponent
@@ -76,9 +171,53 @@ impl StateComponent
 		user_state_component.to_state_component()
 	}
 
+	/// All state components, known or unknown, from bit 0 to bit 62 inclusive.
+	#[inline(always)]
+	pub fn all() -> impl Iterator<Item=StateComponent>
+	{
+		(0 ..= Self::MaximumBit).map(StateComponent)
+	}
+
+	/// Classifies this state component as one known to this crate, or as unknown.
+	#[inline(always)]
+	pub fn kind(self) -> StateComponentKind
+	{
+		use self::NotSizingQueryableUserStateComponent::*;
+		use self::SizingQueryableUserStateComponent::*;
+		use self::StateComponentKind::*;
+		use self::SupervisorStateComponent::*;
+
+		match self.0
+		{
+			0 => NotSizingQueryableUser(x87),
+			1 => NotSizingQueryableUser(SSE),
+			2 => SizingQueryableUser(AVX),
+			3 => SizingQueryableUser(BNDREGS_MPX),
+			4 => SizingQueryableUser(BNDCSR_MPX),
+			5 => SizingQueryableUser(opmask_AVX_512),
+			6 => SizingQueryableUser(ZMM_Hi256_AVX_512),
+			7 => SizingQueryableUser(Hi16_ZMM_AVX_512),
+			8 => Supervisor(PT),
+			9 => SizingQueryableUser(PKRU),
+			10 => Supervisor(PASID),
+			11 => Supervisor(CET_U),
+			12 => Supervisor(CET_S),
+			13 => Supervisor(HDC),
+			14 => Supervisor(UINTR),
+			15 => Supervisor(LBR),
+			16 => Supervisor(HWP),
+			17 => SizingQueryableUser(XTILECFG),
+			18 => SizingQueryableUser(XTILEDATA),
+			19 => SizingQueryableUser(EGPR_APX),
+			62 => SizingQueryableUser(LWP),
+			_ => Unknown(self),
+		}
+	}
+
 	/// Query size using `CPUID` of the state component for `bit`.
 	///
-	/// `bit` must be between 2 and 62 inclusive.
+	/// Always `None` for the `x87` and `SSE` state components, which are in the legacy region.
+	/// Works for state components unknown to this crate.
 	#[inline(always)]
 	pub fn sizing(self) -> Option<StateComponentSizing>
 	{
@@ -93,10 +232,46 @@ impl StateComponent
 		}
 	}
 
+	/// Is this state component supported in `XCR0` (user) or `IA32_XSS` (supervisor)?
+	///
+	/// For state components other than `x87` and `SSE` this is taken from bit 0 of `ECX` returned by `CPUID` leaf `0x0D`, and so works for state components unknown to this crate.
+	///
+	/// Returns `None` if not supported by the processor.
+	#[inline(always)]
+	pub fn supported_in(self) -> Option<SupportedIn>
+	{
+		if self.bit() < 2
+		{
+			Some(SupportedIn::XCR0)
+		}
+		else
+		{
+			self.sizing().map(|sizing| sizing.supported_in)
+		}
+	}
+
+	/// Is this an user state component?
+	///
+	/// Returns `false` if not supported by the processor.
+	#[inline(always)]
+	pub fn is_user(self) -> bool
+	{
+		self.supported_in() == Some(SupportedIn::XCR0)
+	}
+
+	/// Is this a supervisor state component?
+	///
+	/// Returns `false` if not supported by the processor.
+	#[inline(always)]
+	pub fn is_supervisor(self) -> bool
+	{
+		self.supported_in() == Some(SupportedIn::IA32_XSS)
+	}
+
 	/// State component bit value.
 	#[inline(always)]
-	pub fn bit(self) -> u8
+	pub const fn bit(self) -> u8
 	{
-		unsafe { transmute(self) }
+		self.0
 	}
 }
@@ -0,0 +1,39 @@
+// This file is part of x86_64-xsave. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT. No part of x86_64-xsave, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of x86_64-xsave. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/x86_64-xsave/master/COPYRIGHT.
+
+
+/// A state component classified as one known to this crate, or as unknown.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StateComponentKind
+{
+	/// An user state component whose size can not be queried using `CPUID` (`x87` or `SSE`).
+	NotSizingQueryableUser(NotSizingQueryableUserStateComponent),
+
+	/// An user state component whose size can be queried using `CPUID`.
+	SizingQueryableUser(SizingQueryableUserStateComponent),
+
+	/// A supervisor state component.
+	Supervisor(SupervisorStateComponent),
+
+	/// A state component not known to this crate, such as one defined after this crate was released.
+	///
+	/// It can still be sized (`StateComponent.sizing()`), classified as user or supervisor (`StateComponent.supported_in()`) and extracted from a `XSAVE` area (`XSaveAreaLayout.component()`).
+	Unknown(StateComponent),
+}
+
+impl From<StateComponentKind> for StateComponent
+{
+	#[inline(always)]
+	fn from(value: StateComponentKind) -> Self
+	{
+		use self::StateComponentKind::*;
+
+		match value
+		{
+			NotSizingQueryableUser(state_component) => state_component.to_state_component(),
+			SizingQueryableUser(state_component) => state_component.to_state_component(),
+			Supervisor(state_component) => state_component.to_state_component(),
+			Unknown(state_component) => state_component,
+		}
+	}
+}
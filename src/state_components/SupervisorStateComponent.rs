@@ -12,10 +12,32 @@ pub enum SupervisorStateComponent
 	/// The state component used for the Intel Processor Trace MSRs (`PT` state).
 	PT = 8,
 
+	/// The state component used for the process address space identifier `MSR` `IA32_PASID` (`PASID` state).
+	PASID = 10,
+
+	/// The state component used for the user-mode Control-flow Enforcement Technology MSRs `IA32_U_CET` and `IA32_PL3_SSP` (`CET_U` state).
+	CET_U = 11,
+
+	/// The state component used for the supervisor-mode shadow stack pointer MSRs `IA32_PL0_SSP`, `IA32_PL1_SSP` and `IA32_PL2_SSP` (`CET_S` state).
+	CET_S = 12,
+
 	/// The state component used for an `MSR` used to control hardware duty cycling (`HDC` state).
 	///
 	/// See Section 13.5.8 of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture).
 	HDC = 13,
+
+	/// The state component used for the user interrupt `MSR`s and user interrupt flag (`UINTR` state).
+	///
+	/// See Section 13.5.11 of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture).
+	UINTR = 14,
+
+	/// The state component used for the architectural last branch record `MSR`s (`LBR` state).
+	///
+	/// See Section 13.5.12 of the Intel® 64 and IA-32 Architectures Software Developer's Manual Volume 1 (Basic Architecture).
+	LBR = 15,
+
+	/// The state component used for the hardware-controlled performance states `MSR` `IA32_HWP_REQUEST` (`HWP` state).
+	HWP = 16,
 }
 
 impl SupervisorStateComponent
@@ -24,10 +46,7 @@ impl SupervisorStateComponent
 	#[inline(always)]
 	pub const fn to_state_component(self) -> StateComponent
 	{
-		StateComponent
-		{
-			supervisor: self,
-		}
+		StateComponent(self as u8)
 	}
 
 	/// Query size using `CPUID` of this supervisor state component.
@@ -11,6 +11,9 @@ pub union UserStateComponent
 
 	/// An user state component whose size can be queried using `CPUID`.
 	pub sizing_queryable: SizingQueryableUserStateComponent,
+
+	/// Both variants are `#[repr(u8)]` enums whose discriminant is the state component bit.
+	bit: u8,
 }
 
 impl UserStateComponent
@@ -42,13 +45,22 @@ impl UserStateComponent
 	/// `PKRU` user state component.
 	pub const PKRU: UserStateComponent = SizingQueryableUserStateComponent::to_user_state_component(SizingQueryableUserStateComponent::PKRU);
 
+	/// `XTILECFG` user state component.
+	pub const XTILECFG: UserStateComponent = SizingQueryableUserStateComponent::to_user_state_component(SizingQueryableUserStateComponent::XTILECFG);
+
+	/// `XTILEDATA` user state component.
+	pub const XTILEDATA: UserStateComponent = SizingQueryableUserStateComponent::to_user_state_component(SizingQueryableUserStateComponent::XTILEDATA);
+
+	/// `EGPR_APX` user state component.
+	pub const EGPR_APX: UserStateComponent = SizingQueryableUserStateComponent::to_user_state_component(SizingQueryableUserStateComponent::EGPR_APX);
+
+	/// `LWP` user state component.
+	pub const LWP: UserStateComponent = SizingQueryableUserStateComponent::to_user_state_component(SizingQueryableUserStateComponent::LWP);
+
 	/// To a state component.
 	#[inline(always)]
 	pub const fn to_state_component(self) -> StateComponent
 	{
-		StateComponent
-		{
-			user: self
-		}
+		StateComponent(unsafe { self.bit })
 	}
 }
@@ -8,6 +8,7 @@ use super::*;
 include!("NotSizingQueryableUserStateComponent.rs");
 include!("SizingQueryableUserStateComponent.rs");
 include!("StateComponent.rs");
+include!("StateComponentKind.rs");
 include!("StateComponentSizing.rs");
 include!("SupervisorStateComponent.rs");
 include!("SupportedIn.rs");